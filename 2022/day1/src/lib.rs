@@ -0,0 +1,54 @@
+use eyre::Report;
+use std::fmt::Display;
+use utils::{parse::read_lines, solution::Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        elf_totals(input)
+    }
+
+    fn part1(elf_totals: &Self::Input) -> Result<impl Display, Report> {
+        Ok(top_total(elf_totals, 1))
+    }
+
+    fn part2(elf_totals: &Self::Input) -> Result<impl Display, Report> {
+        Ok(top_total(elf_totals, 3))
+    }
+}
+
+/// Finds the total calories carried by each elf.
+fn elf_totals(input: &str) -> Result<Vec<u32>, Report> {
+    let mut elf_totals = vec![];
+    let mut current_elf_sum: u32 = 0;
+    for line in read_lines(input.as_bytes())? {
+        if line.is_empty() {
+            elf_totals.push(current_elf_sum);
+            current_elf_sum = 0;
+        } else {
+            current_elf_sum += line.parse::<u32>()?;
+        }
+    }
+    if current_elf_sum > 0 {
+        elf_totals.push(current_elf_sum);
+    }
+    Ok(elf_totals)
+}
+
+/// Returns the sum of the `count` largest totals.
+fn top_total(elf_totals: &[u32], count: usize) -> u32 {
+    let mut elf_totals = elf_totals.to_owned();
+    elf_totals.sort_by(|a, b| b.cmp(a));
+    elf_totals.iter().take(count).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day1, example);
+}
@@ -0,0 +1,75 @@
+use eyre::{OptionExt, Report};
+use std::{collections::HashMap, fmt::Display};
+use utils::{parse::parse_lines, solution::Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// The left and right lists, both sorted.
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        // Parse lines, splitting into two lists.
+        let (mut left, mut right): (Vec<_>, Vec<_>) = parse_lines(input.as_bytes(), |line| {
+            let (left, right) = line.split_once(' ').ok_or_eyre("Missing delimiter")?;
+            Ok((left.parse::<u64>()?, right.trim().parse::<u64>()?))
+        })?
+        .into_iter()
+        .unzip();
+
+        // Sort them both.
+        left.sort();
+        right.sort();
+
+        Ok((left, right))
+    }
+
+    fn part1((left, right): &Self::Input) -> Result<impl Display, Report> {
+        Ok(total_distance(left, right))
+    }
+
+    fn part2((left, right): &Self::Input) -> Result<impl Display, Report> {
+        Ok(similarity_score(left, right))
+    }
+}
+
+/// Calculates and sums the distances.
+fn total_distance(left_sorted: &[u64], right_sorted: &[u64]) -> u64 {
+    left_sorted
+        .iter()
+        .zip(right_sorted)
+        .map(|(left, right)| left.abs_diff(*right))
+        .sum()
+}
+
+/// Calculates the total similarity score.
+fn similarity_score(left: &[u64], right: &[u64]) -> u64 {
+    let mut right_counts: HashMap<u64, u64> = HashMap::new();
+    for right in right {
+        *right_counts.entry(*right).or_default() += 1;
+    }
+    left.iter()
+        .map(|left| left * right_counts.get(left).copied().unwrap_or_default())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day1, example);
+
+    #[test]
+    fn example_distance() {
+        assert_eq!(total_distance(&[1, 2, 3, 3, 3, 4], &[3, 3, 3, 4, 5, 9]), 11);
+    }
+
+    #[test]
+    fn example_similarity() {
+        assert_eq!(
+            similarity_score(&[1, 2, 3, 3, 3, 4], &[3, 3, 3, 4, 5, 9]),
+            31
+        );
+    }
+}
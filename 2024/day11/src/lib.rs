@@ -0,0 +1,100 @@
+use eyre::Report;
+use std::{collections::HashMap, fmt::Display};
+use utils::{args::Params, solution::Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    /// The stones, and how many times to blink for part 2.
+    type Input = (Vec<u64>, usize);
+
+    const PARAMS: &[&str] = &["blinks"];
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        Self::parse_with_params(input, &Params::default())
+    }
+
+    fn parse_with_params(input: &str, params: &Params) -> Result<Self::Input, Report> {
+        let stones = input
+            .split_whitespace()
+            .map(|stone| Ok(stone.parse()?))
+            .collect::<Result<_, Report>>()?;
+        Ok((stones, params.get("blinks", 75)?))
+    }
+
+    fn part1((stones, _): &Self::Input) -> Result<impl Display, Report> {
+        Ok(count_after_blinks(stones, 25))
+    }
+
+    fn part2((stones, blinks): &Self::Input) -> Result<impl Display, Report> {
+        Ok(count_after_blinks(stones, *blinks))
+    }
+}
+
+/// Returns the number of stones after blinking the given number of times.
+fn count_after_blinks(stones: &[u64], blinks: usize) -> usize {
+    let mut stone_counts = count(stones);
+    for _ in 0..blinks {
+        stone_counts = blink(&stone_counts);
+    }
+    stone_counts.values().sum()
+}
+
+fn count(stones: &[u64]) -> HashMap<u64, usize> {
+    let mut counts = HashMap::new();
+    for stone in stones {
+        *counts.entry(*stone).or_default() += 1;
+    }
+    counts
+}
+
+fn blink(stone_counts: &HashMap<u64, usize>) -> HashMap<u64, usize> {
+    let mut new_counts = HashMap::new();
+    for (stone, count) in stone_counts {
+        if *stone == 0 {
+            *new_counts.entry(1).or_default() += count;
+        } else if let Some((a, b)) = split(*stone) {
+            *new_counts.entry(a).or_default() += count;
+            *new_counts.entry(b).or_default() += count;
+        } else {
+            *new_counts.entry(stone * 2024).or_default() += count;
+        }
+    }
+    new_counts
+}
+
+fn split(stone: u64) -> Option<(u64, u64)> {
+    let digit_count = stone.ilog10() + 1;
+    if !digit_count.is_multiple_of(2) {
+        None
+    } else {
+        let high_multiplier = 10u64.pow(digit_count / 2);
+        let high = stone / high_multiplier;
+        let low = stone - high * high_multiplier;
+        Some((high, low))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day11, example, six_blinks);
+
+    #[test]
+    fn blink_example() {
+        let stones = vec![125, 17];
+        let stone_counts = count(&stones);
+        let stone_counts = blink(&stone_counts);
+        assert_eq!(stone_counts, count(&[253000, 1, 7]));
+        let stone_counts = blink(&stone_counts);
+        assert_eq!(stone_counts, count(&[253, 0, 2024, 14168]));
+        let stone_counts = blink(&stone_counts);
+        assert_eq!(stone_counts, count(&[512072, 1, 20, 24, 28676032]));
+        let stone_counts = blink(&stone_counts);
+        let stone_counts = blink(&stone_counts);
+        let stone_counts = blink(&stone_counts);
+        assert_eq!(stone_counts.values().sum::<usize>(), 22);
+    }
+}
@@ -0,0 +1,70 @@
+//! Generates synthetic lists of robots.
+
+use std::fmt::Write;
+use utils::random::Rng;
+
+/// Generates the given number of robots in a space of the given size, which all end up in
+/// different positions after the given number of seconds as if forming the easter egg.
+///
+/// There can't be more robots than positions in the space.
+pub fn generate(rng: &mut Rng, robots: usize, width: i64, height: i64, seconds: i64) -> String {
+    let mut positions = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .collect::<Vec<_>>();
+    assert!(robots <= positions.len(), "Too many robots for the space");
+    rng.shuffle(&mut positions);
+
+    let mut list = String::new();
+    for &(x, y) in &positions[..robots] {
+        let velocity = (
+            rng.range_i64(1 - width..width),
+            rng.range_i64(1 - height..height),
+        );
+        // Run the robot backwards from where it should end up.
+        let start = (
+            (x - velocity.0 * seconds).rem_euclid(width),
+            (y - velocity.1 * seconds).rem_euclid(height),
+        );
+        writeln!(
+            list,
+            "p={},{} v={},{}",
+            start.0, start.1, velocity.0, velocity.1
+        )
+        .unwrap();
+    }
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{contains_easter_egg, find_easter_egg, parse, run};
+
+    #[test]
+    fn generated_robots() {
+        let mut rng = Rng::new(14);
+        for (robots, width, height, seconds) in
+            [(1, 11, 7, 0), (20, 11, 7, 30), (400, 101, 103, 5000)]
+        {
+            let list = generate(&mut rng, robots, width, height, seconds);
+            let original = parse(list.as_bytes()).unwrap();
+            assert_eq!(original.len(), robots);
+
+            let mut robots = original.clone();
+            run(&mut robots, seconds as u64, width, height);
+            assert!(contains_easter_egg(&robots, width, height));
+            for (robot, original) in robots.iter().zip(&original) {
+                assert_eq!(
+                    robot.position,
+                    (
+                        (original.position.0 + original.velocity.0 * seconds).rem_euclid(width),
+                        (original.position.1 + original.velocity.1 * seconds).rem_euclid(height),
+                    )
+                );
+            }
+
+            let mut robots = original;
+            assert!(find_easter_egg(&mut robots, width, height).unwrap() <= seconds as u64);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use eyre::{bail, eyre, Report};
+use regex::Regex;
+use std::{fmt::Display, io::BufRead};
+use utils::{args::Params, budget::progress, grid::Grid, solution::Solution, verbose};
+
+pub mod generate;
+
+const WIDTH: i64 = 101;
+const HEIGHT: i64 = 103;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Bathroom;
+
+    const PARAMS: &[&str] = &["width", "height"];
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        Self::parse_with_params(input, &Params::default())
+    }
+
+    fn parse_with_params(input: &str, params: &Params) -> Result<Self::Input, Report> {
+        let width = params.get("width", WIDTH)?;
+        let height = params.get("height", HEIGHT)?;
+        if width <= 0 || height <= 0 || width.checked_mul(height).is_none() {
+            bail!("Invalid size {}x{}", width, height);
+        }
+        let robots = parse(input.as_bytes())?;
+        if let Some(robot) = robots.iter().find(|robot| {
+            !(0..width).contains(&robot.position.0) || !(0..height).contains(&robot.position.1)
+        }) {
+            bail!(
+                "Robot at {},{} is outside the {}x{} space",
+                robot.position.0,
+                robot.position.1,
+                width,
+                height
+            );
+        }
+        Ok(Bathroom {
+            robots,
+            width,
+            height,
+        })
+    }
+
+    fn part1(bathroom: &Self::Input) -> Result<impl Display, Report> {
+        let mut robots = bathroom.robots.clone();
+        run(&mut robots, 100, bathroom.width, bathroom.height);
+        Ok(safety_factor(&robots, bathroom.width, bathroom.height))
+    }
+
+    fn part2(bathroom: &Self::Input) -> Result<impl Display, Report> {
+        let mut robots = bathroom.robots.clone();
+        find_easter_egg(&mut robots, bathroom.width, bathroom.height)
+    }
+}
+
+/// The robots, and the size of the space they move around.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bathroom {
+    robots: Vec<Robot>,
+    width: i64,
+    height: i64,
+}
+
+/// Runs the robots until they show the easter egg, and returns the number of seconds this took,
+/// or an error if they never do.
+///
+/// The easter egg is printed if verbose output is enabled.
+fn find_easter_egg(robots: &mut [Robot], width: i64, height: i64) -> Result<u64, Report> {
+    let mut seconds = 0;
+    while !contains_easter_egg(robots, width, height) {
+        // Every robot is back where it started after `width * height` seconds, so they will
+        // never form a picture if they haven't by then.
+        if seconds == width * height {
+            bail!("The robots never form a picture");
+        }
+        progress()?;
+        step_all(robots, width, height);
+        seconds += 1;
+    }
+    verbose!("{}", format_robots(robots, width, height));
+    Ok(seconds.try_into()?)
+}
+
+fn contains_easter_egg(robots: &[Robot], width: i64, height: i64) -> bool {
+    let grid = robot_grid(robots, width, height);
+    let no_overlaps = grid.elements().all(|(_, _, count)| *count <= 1);
+    no_overlaps
+}
+
+fn robot_grid(robots: &[Robot], width: i64, height: i64) -> Grid<usize> {
+    let mut grid: Grid<usize> = Grid::new(width.try_into().unwrap(), height.try_into().unwrap());
+    for robot in robots {
+        *grid
+            .get_mut(
+                robot.position.0.try_into().unwrap(),
+                robot.position.1.try_into().unwrap(),
+            )
+            .unwrap() += 1;
+    }
+    grid
+}
+
+fn format_robots(robots: &[Robot], width: i64, height: i64) -> String {
+    let grid = robot_grid(robots, width, height);
+    let mut picture = String::new();
+    for row in grid.rows() {
+        for entry in row {
+            if *entry == 0 {
+                picture.push('.');
+            } else {
+                picture.push_str(&entry.to_string());
+            }
+        }
+        picture.push('\n');
+    }
+    picture
+}
+
+/// Runs the given robots for the given number of seconds.
+fn run(robots: &mut [Robot], seconds: u64, width: i64, height: i64) {
+    for _ in 0..seconds {
+        step_all(robots, width, height);
+    }
+}
+
+fn step_all(robots: &mut [Robot], width: i64, height: i64) {
+    for robot in &mut *robots {
+        robot.step(width, height);
+    }
+}
+
+fn parse(input: impl BufRead) -> Result<Vec<Robot>, Report> {
+    let pattern = Regex::new(r"p=([0-9-]+),([0-9-]+) v=([0-9-]+),([0-9-]+)").unwrap();
+    input
+        .lines()
+        .map(|line| {
+            let line = line?;
+            let captures = pattern
+                .captures(&line)
+                .ok_or_else(|| eyre!("Invalid line: '{}'", line))?;
+            Ok(Robot {
+                position: (
+                    captures.get(1).unwrap().as_str().parse()?,
+                    captures.get(2).unwrap().as_str().parse()?,
+                ),
+                velocity: (
+                    captures.get(3).unwrap().as_str().parse()?,
+                    captures.get(4).unwrap().as_str().parse()?,
+                ),
+            })
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Robot {
+    position: (i64, i64),
+    velocity: (i64, i64),
+}
+
+impl Robot {
+    /// Moves the robot one step, i.e. for one second, wrapping around the edges of the space.
+    fn step(&mut self, width: i64, height: i64) {
+        self.position.0 = (self.position.0 + self.velocity.0).rem_euclid(width);
+        self.position.1 = (self.position.1 + self.velocity.1).rem_euclid(height);
+    }
+}
+
+fn safety_factor(robots: &[Robot], width: i64, height: i64) -> u64 {
+    // Top left, top right, bottom left, bottom right.
+    let mut quadrant_counts = [0; 4];
+    for robot in robots {
+        if robot.position.1 * 2 == height - 1 || robot.position.0 * 2 == width - 1 {
+            // Ignore robot exactly in the middle.
+        } else if robot.position.1 * 2 < height {
+            if robot.position.0 * 2 < width {
+                quadrant_counts[0] += 1;
+            } else {
+                quadrant_counts[1] += 1;
+            }
+        } else {
+            if robot.position.0 * 2 < width {
+                quadrant_counts[2] += 1;
+            } else {
+                quadrant_counts[3] += 1;
+            }
+        }
+    }
+    quadrant_counts.into_iter().product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::{
+        budget::{with_budget, Budget, Timeout},
+        example_tests,
+    };
+
+    example_tests!(Day14, example);
+
+    #[test]
+    fn parse_small() {
+        assert_eq!(
+            parse(
+                "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+"
+                .as_bytes()
+            )
+            .unwrap(),
+            vec![
+                Robot {
+                    position: (0, 4),
+                    velocity: (3, -3),
+                },
+                Robot {
+                    position: (6, 3),
+                    velocity: (-1, -3),
+                },
+                Robot {
+                    position: (10, 3),
+                    velocity: (-1, 2),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn run_example() {
+        let mut robots = parse(include_str!("../examples/example.txt").as_bytes()).unwrap();
+        run(&mut robots, 100, 11, 7);
+        assert_eq!(safety_factor(&robots, 11, 7), 12);
+    }
+
+    #[test]
+    fn invalid_params() {
+        let input = "p=50,50 v=1,1\n";
+        let params = |width: &str, height: &str| {
+            let mut params = Params::default();
+            params.insert("width", width);
+            params.insert("height", height);
+            params
+        };
+        assert!(Day14::parse_with_params(input, &params("101", "103")).is_ok());
+        assert!(Day14::parse_with_params(input, &params("11", "7")).is_err());
+        assert!(Day14::parse_with_params(input, &params("0", "103")).is_err());
+        assert!(Day14::parse_with_params(input, &params("101", "-1")).is_err());
+    }
+
+    #[test]
+    fn fast_robot() {
+        let mut robot = Robot {
+            position: (1, 1),
+            velocity: (25, -15),
+        };
+        robot.step(11, 7);
+        assert_eq!(robot.position, (4, 0));
+    }
+
+    #[test]
+    fn no_easter_egg() {
+        let robot = Robot {
+            position: (0, 0),
+            velocity: (1, 0),
+        };
+        assert!(find_easter_egg(&mut [robot.clone(), robot.clone()], 11, 7).is_err());
+
+        let budget = Budget {
+            time: None,
+            steps: Some(10),
+        };
+        let e = with_budget(budget, || {
+            find_easter_egg(&mut [robot.clone(), robot.clone()], 11, 7)
+        })
+        .unwrap_err();
+        assert!(e.is::<Timeout>());
+    }
+}
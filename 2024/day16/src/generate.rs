@@ -0,0 +1,122 @@
+//! Generates synthetic mazes.
+
+use utils::random::Rng;
+
+/// Generates a maze with the start in the bottom-left corner and the end in the top-right, as in
+/// the puzzle input.
+///
+/// The width and height are rounded up to odd numbers of at least 5. A perfect maze is carved by
+/// a randomised depth-first search, and then each remaining inner wall between two corridors is
+/// knocked through with the given probability so that there are several routes.
+pub fn generate(rng: &mut Rng, width: usize, height: usize, openness: f64) -> String {
+    let width = width.max(5) | 1;
+    let height = height.max(5) | 1;
+    let mut maze = vec![vec!['#'; width]; height];
+
+    let start = (1, height - 2);
+    maze[start.1][start.0] = '.';
+    let mut stack = vec![start];
+    while let Some(&(x, y)) = stack.last() {
+        let mut neighbours = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let x = x.checked_add_signed(dx)?;
+                let y = y.checked_add_signed(dy)?;
+                (x < width - 1 && y < height - 1 && maze[y][x] == '#').then_some((x, y))
+            })
+            .collect::<Vec<_>>();
+        if neighbours.is_empty() {
+            stack.pop();
+            continue;
+        }
+        rng.shuffle(&mut neighbours);
+        let (next_x, next_y) = neighbours[0];
+        maze[(y + next_y) / 2][(x + next_x) / 2] = '.';
+        maze[next_y][next_x] = '.';
+        stack.push((next_x, next_y));
+    }
+
+    for (y, row) in maze.iter_mut().enumerate().take(height - 1).skip(1) {
+        for (x, cell) in row.iter_mut().enumerate().take(width - 1).skip(1) {
+            let between_corridors = (x % 2 == 0 && y % 2 == 1) || (x % 2 == 1 && y % 2 == 0);
+            if *cell == '#' && between_corridors && rng.chance(openness) {
+                *cell = '.';
+            }
+        }
+    }
+
+    maze[start.1][start.0] = 'S';
+    maze[1][width - 2] = 'E';
+    maze.into_iter()
+        .map(|row| row.into_iter().chain(['\n']).collect::<String>())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::best_path_score;
+    use std::{
+        cmp::Reverse,
+        collections::{BinaryHeap, HashMap},
+    };
+    use utils::{grid::Grid, parse_chargrid, Direction};
+
+    /// Finds the best score with Dijkstra's algorithm over positions and directions.
+    fn dijkstra_score(maze: &Grid<char>) -> Option<u64> {
+        let start = maze
+            .elements()
+            .find_map(|(x, y, e)| (*e == 'S').then_some((x, y)))?;
+        // Directions are stored as indices into `Direction::ALL`, as they aren't ordered.
+        let index = |direction| Direction::ALL.iter().position(|&d| d == direction).unwrap();
+        let mut best = HashMap::new();
+        let mut queue = BinaryHeap::from([Reverse((0, start, index(Direction::Right)))]);
+        while let Some(Reverse((score, position, direction))) = queue.pop() {
+            if *maze.get(position.0, position.1).unwrap() == 'E' {
+                return Some(score);
+            }
+            if best
+                .get(&(position, direction))
+                .is_some_and(|&b| b <= score)
+            {
+                continue;
+            }
+            best.insert((position, direction), score);
+            let direction = Direction::ALL[direction];
+            for turned in [
+                direction.rotate_clockwise(),
+                direction.rotate_anticlockwise(),
+            ] {
+                queue.push(Reverse((score + 1000, position, index(turned))));
+            }
+            if let Some(ahead) = direction.move_from(position, maze.width(), maze.height()) {
+                if *maze.get(ahead.0, ahead.1).unwrap() != '#' {
+                    queue.push(Reverse((score + 1, ahead, index(direction))));
+                }
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn generated_mazes() {
+        let mut rng = Rng::new(16);
+        for (width, height) in [(5, 5), (8, 6), (15, 15), (21, 15)] {
+            let maze = parse_chargrid(generate(&mut rng, width, height, 0.0).as_bytes()).unwrap();
+            assert_eq!(maze.width(), width | 1);
+            assert_eq!(maze.height(), height | 1);
+            assert_eq!(best_path_score(&maze).ok(), dijkstra_score(&maze));
+        }
+    }
+
+    #[test]
+    fn generated_mazes_with_loops() {
+        // `best_path_score` takes far too long on mazes with loops, as for `example2`, so these
+        // are only checked for having a path.
+        let mut rng = Rng::new(61);
+        for _ in 0..5 {
+            let maze = parse_chargrid(generate(&mut rng, 15, 15, 0.3).as_bytes()).unwrap();
+            assert!(dijkstra_score(&maze).is_some());
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use eyre::{OptionExt, Report};
+use std::{cmp::min, fmt::Display};
+use utils::{
+    grid::Grid,
+    memo::{Cycle, HashMemo},
+    parse_chargrid,
+    solution::{not_solved, Solution},
+    trace, verbose, Direction,
+};
+
+pub mod generate;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse_chargrid(input.as_bytes())
+    }
+
+    fn part1(maze: &Self::Input) -> Result<impl Display, Report> {
+        best_path_score(maze)
+    }
+
+    fn part2(_maze: &Self::Input) -> Result<impl Display, Report> {
+        not_solved()
+    }
+}
+
+/// Returns the score of the best path through the maze.
+fn best_path_score(maze: &Grid<char>) -> Result<u64, Report> {
+    let start = maze
+        .elements()
+        .find_map(|(x, y, e)| if *e == 'S' { Some((x, y)) } else { None })
+        .ok_or_eyre("No start point")?;
+    let mut memo = HashMemo::new();
+    let score = best_score_from_memoised(maze, start, Direction::Right, &mut memo, 0);
+    verbose!("Memo: {}", memo.stats());
+    // Every path looping back on itself also means there is no path to the end.
+    score.ok().flatten().ok_or_eyre("No path to end")
+}
+
+/// The best score from each position and direction, or `None` if the end is unreachable from it.
+type ScoreMemo = HashMemo<((usize, usize), Direction), Option<u64>>;
+
+/// Finds the best score starting from the given position, or returns a `Cycle` error if every
+/// path from it loops back to a position and direction already being searched.
+///
+/// Scores found while skipping such loops are cached too, so a later lookup may get a score which
+/// ignores a path through a position that was being searched at the time. See
+/// [`Memo::try_get_or_compute`](utils::memo::Memo::try_get_or_compute).
+fn best_score_from_memoised(
+    maze: &Grid<char>,
+    start: (usize, usize),
+    direction: Direction,
+    memo: &mut ScoreMemo,
+    depth: usize,
+) -> Result<Option<u64>, Cycle> {
+    memo.try_get_or_compute(&(start, direction), |memo, &(start, direction)| {
+        best_score_from(maze, start, direction, memo, depth)
+    })
+}
+
+/// Finds the best score starting from the given position, by a recursive depth-first search.
+fn best_score_from(
+    maze: &Grid<char>,
+    start: (usize, usize),
+    direction: Direction,
+    memo: &mut ScoreMemo,
+    depth: usize,
+) -> Result<Option<u64>, Cycle> {
+    trace!(
+        "{}Looking {:?} from {:?}",
+        " ".repeat(depth),
+        direction,
+        start
+    );
+    if *maze.get(start.0, start.1).unwrap() == 'E' {
+        trace!("{}Reached end", " ".repeat(depth));
+        return Ok(Some(0));
+    }
+    let mut moves = vec![
+        (start, direction.rotate_clockwise(), 1000),
+        (start, direction.rotate_anticlockwise(), 1000),
+    ];
+    if let Some(ahead) = direction.move_from(start, maze.width(), maze.height()) {
+        if *maze.get(ahead.0, ahead.1).unwrap() != '#' {
+            moves.push((ahead, direction, 1));
+        }
+    }
+    let mut best = None;
+    let mut looped = None;
+    for (position, direction, cost) in moves {
+        match best_score_from_memoised(maze, position, direction, memo, depth + 1) {
+            Ok(Some(score)) => {
+                best = Some(best.map_or(score + cost, |best| min(best, score + cost)))
+            }
+            Ok(None) => {}
+            Err(cycle) => looped = Some(cycle),
+        }
+    }
+    match (best, looped) {
+        (None, Some(cycle)) => Err(cycle),
+        (best, _) => Ok(best),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day16, minimal, example);
+
+    #[test]
+    fn minimal_score() {
+        let maze = parse_chargrid(include_str!("../examples/minimal.txt").as_bytes()).unwrap();
+        assert_eq!(best_path_score(&maze).unwrap(), 1002);
+    }
+
+    #[test]
+    fn example_score() {
+        let maze = parse_chargrid(include_str!("../examples/example.txt").as_bytes()).unwrap();
+        assert_eq!(best_path_score(&maze).unwrap(), 7036);
+    }
+
+    #[test]
+    #[ignore]
+    fn example2_score() {
+        let maze = parse_chargrid(include_str!("../examples/example2.txt").as_bytes()).unwrap();
+        assert_eq!(best_path_score(&maze).unwrap(), 11048);
+    }
+}
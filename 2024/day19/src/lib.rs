@@ -0,0 +1,123 @@
+use eyre::{bail, OptionExt, Report};
+use std::{cmp::min, collections::HashSet, fmt::Display, io::BufRead};
+use utils::{memo::HashMemo, solution::Solution, verbose};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    /// The available towels and the desired designs.
+    type Input = (HashSet<String>, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse(input.as_bytes())
+    }
+
+    fn part1((towels, designs): &Self::Input) -> Result<impl Display, Report> {
+        let all_arrangements = count_all_arrangements(towels, designs);
+        Ok(all_arrangements.iter().filter(|count| **count > 0).count())
+    }
+
+    fn part2((towels, designs): &Self::Input) -> Result<impl Display, Report> {
+        let all_arrangements = count_all_arrangements(towels, designs);
+        Ok(all_arrangements.iter().sum::<usize>())
+    }
+}
+
+fn parse(input: impl BufRead) -> Result<(HashSet<String>, Vec<String>), Report> {
+    let mut lines = input.lines();
+    let towels = lines
+        .next()
+        .ok_or_eyre("Empty input")??
+        .split(", ")
+        .map(|s| s.to_owned())
+        .collect();
+
+    if !lines.next().ok_or_eyre("Missing blank line")??.is_empty() {
+        bail!("Second line is not blank");
+    }
+    let designs = lines.collect::<Result<_, _>>()?;
+
+    Ok((towels, designs))
+}
+
+/// Returns how many different ways each design is possible.
+fn count_all_arrangements(towels: &HashSet<String>, designs: &[String]) -> Vec<usize> {
+    let Some(max_towel_size) = towels.iter().map(|towel| towel.len()).max() else {
+        return vec![0; designs.len()];
+    };
+    let mut memo = HashMemo::new();
+    let counts = designs
+        .iter()
+        .map(|design| count_possible_arrangements(towels, max_towel_size, design, &mut memo))
+        .collect();
+    verbose!("Memo: {}", memo.stats());
+    counts
+}
+
+/// Returns the number of ways it is possible to make the given design from the given towels.
+fn count_possible_arrangements(
+    towels: &HashSet<String>,
+    max_towel_size: usize,
+    design: &str,
+    memo: &mut HashMemo<String, usize>,
+) -> usize {
+    if design.is_empty() {
+        return 1;
+    }
+    memo.get_or_compute(design, |memo, design| {
+        (1..=min(max_towel_size, design.len()))
+            .map(|prefix_len| {
+                if towels.contains(&design[..prefix_len]) {
+                    count_possible_arrangements(towels, max_towel_size, &design[prefix_len..], memo)
+                } else {
+                    0
+                }
+            })
+            .sum()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day19, example);
+
+    #[test]
+    fn count_example() {
+        let (towels, designs) = parse(include_str!("../examples/example.txt").as_bytes()).unwrap();
+        assert_eq!(
+            towels,
+            [
+                "r".to_string(),
+                "wr".to_string(),
+                "b".to_string(),
+                "g".to_string(),
+                "bwu".to_string(),
+                "rb".to_string(),
+                "gb".to_string(),
+                "br".to_string(),
+            ]
+            .into_iter()
+            .collect()
+        );
+        assert_eq!(
+            designs,
+            vec![
+                "brwrr".to_string(),
+                "bggr".to_string(),
+                "gbbr".to_string(),
+                "rrbgbr".to_string(),
+                "ubwu".to_string(),
+                "bwurrg".to_string(),
+                "brgr".to_string(),
+                "bbrgwb".to_string(),
+            ]
+        );
+        assert_eq!(
+            count_all_arrangements(&towels, &designs),
+            vec![2, 1, 4, 6, 0, 1, 2, 0]
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use eyre::Report;
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Deref, RangeInclusive},
+};
+use utils::{parse::parse_lines, solution::Solution};
+
+const SAFE_CHANGE_RANGE: RangeInclusive<u64> = 1..=3;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse_lines(input.as_bytes(), |line| {
+            line.split_whitespace()
+                .map(|level| Ok(level.parse()?))
+                .collect()
+        })
+    }
+
+    fn part1(reports: &Self::Input) -> Result<impl Display, Report> {
+        Ok(count(reports, safe))
+    }
+
+    fn part2(reports: &Self::Input) -> Result<impl Display, Report> {
+        Ok(count(reports, safe_with_dampener))
+    }
+}
+
+fn count<'a, E: Deref<Target = D> + 'a, D: ?Sized>(
+    elements: impl IntoIterator<Item = &'a E>,
+    f: impl Fn(&D) -> bool,
+) -> usize {
+    elements.into_iter().filter(|report| f(report)).count()
+}
+
+/// Returns whether the levels are either all increasing or all decreasing, and adjacent levels
+/// differ by `SAFE_CHANGE_RANGE`.
+fn safe(levels: &[u64]) -> bool {
+    if levels.len() < 2 {
+        return true;
+    };
+    let first_direction = levels[0].cmp(&levels[1]);
+    first_direction != Ordering::Equal
+        && levels.windows(2).all(|window| {
+            let [a, b] = window else { unreachable!() };
+            SAFE_CHANGE_RANGE.contains(&a.abs_diff(*b)) && a.cmp(b) == first_direction
+        })
+}
+
+/// Returns whether the levels are safe if one is removed.
+fn safe_with_dampener(levels: &[u64]) -> bool {
+    (0..levels.len()).any(|level_to_remove| {
+        let mut levels = levels.to_vec();
+        levels.remove(level_to_remove);
+        safe(&levels)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day2, example);
+
+    #[test]
+    fn safety_example() {
+        assert!(safe(&[7, 6, 4, 2, 1]));
+        assert!(!safe(&[1, 2, 7, 8, 9]));
+        assert!(!safe(&[9, 7, 6, 2, 1]));
+        assert!(!safe(&[1, 3, 2, 4, 5]));
+        assert!(!safe(&[8, 6, 4, 4, 1]));
+        assert!(safe(&[1, 3, 6, 7, 9]));
+    }
+
+    #[test]
+    fn safety_empty() {
+        assert!(safe(&[]));
+    }
+
+    #[test]
+    fn safety_single() {
+        assert!(safe(&[0]));
+        assert!(safe(&[1]));
+    }
+
+    #[test]
+    fn safety_dampener_example() {
+        assert!(safe_with_dampener(&[7, 6, 4, 2, 1]));
+        assert!(!safe_with_dampener(&[1, 2, 7, 8, 9]));
+        assert!(!safe_with_dampener(&[9, 7, 6, 2, 1]));
+        assert!(safe_with_dampener(&[1, 3, 2, 4, 5]));
+        assert!(safe_with_dampener(&[8, 6, 4, 4, 1]));
+        assert!(safe_with_dampener(&[1, 3, 6, 7, 9]));
+    }
+}
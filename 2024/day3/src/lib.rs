@@ -0,0 +1,121 @@
+use eyre::Report;
+use regex::{Captures, Regex};
+use std::fmt::Display;
+use utils::solution::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<impl Display, Report> {
+        Ok(run_without_disable(instructions))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<impl Display, Report> {
+        Ok(run(instructions))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Do,
+    Dont,
+    Mul(u64, u64),
+}
+
+/// A pattern for an instruction, and a function to make the instruction from its captures.
+type Pattern = (Regex, fn(&Captures) -> Result<Instruction, Report>);
+
+fn parse(mut input: &str) -> Result<Vec<Instruction>, Report> {
+    let patterns: &[Pattern] = &[
+        (Regex::new(r"^do\(\)").unwrap(), |_| Ok(Instruction::Do)),
+        (Regex::new(r"^don't\(\)").unwrap(), |_| {
+            Ok(Instruction::Dont)
+        }),
+        (
+            // `\d` would also match non-ASCII digits, which `parse` doesn't accept.
+            Regex::new(r"^mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap(),
+            |captures| {
+                Ok(Instruction::Mul(
+                    captures[1].parse::<u64>()?,
+                    captures[2].parse::<u64>()?,
+                ))
+            },
+        ),
+    ];
+
+    let mut instructions = Vec::new();
+    'parse: while let Some(next) = input.chars().next() {
+        for (regex, f) in patterns {
+            if let Some(captures) = regex.captures(input) {
+                instructions.push(f(&captures)?);
+                input = &input[captures[0].len()..];
+                continue 'parse;
+            }
+        }
+        input = &input[next.len_utf8()..];
+    }
+    Ok(instructions)
+}
+
+fn run<'a>(instructions: impl IntoIterator<Item = &'a Instruction>) -> u64 {
+    let mut enabled = true;
+    let mut sum = 0;
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => {
+                enabled = true;
+            }
+            Instruction::Dont => {
+                enabled = false;
+            }
+            Instruction::Mul(a, b) => {
+                if enabled {
+                    sum += a * b;
+                }
+            }
+        }
+    }
+    sum
+}
+
+fn run_without_disable(instructions: &[Instruction]) -> u64 {
+    run(instructions
+        .iter()
+        .filter(|instruction| matches!(instruction, Instruction::Mul(_, _))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day3, example, example2);
+
+    #[test]
+    fn calculate_example() {
+        let instructions = parse(include_str!("../examples/example.txt")).unwrap();
+        assert_eq!(run(&instructions), 161);
+        assert_eq!(run_without_disable(&instructions), 161);
+    }
+
+    #[test]
+    fn calculate_example2() {
+        let instructions = parse(include_str!("../examples/example2.txt")).unwrap();
+        assert_eq!(run(&instructions), 48);
+        assert_eq!(run_without_disable(&instructions), 161);
+    }
+
+    #[test]
+    fn parse_non_ascii() {
+        assert_eq!(
+            parse("é mul(1,2)mul(١,2)").unwrap(),
+            vec![Instruction::Mul(1, 2)]
+        );
+    }
+}
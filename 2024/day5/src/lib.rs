@@ -0,0 +1,199 @@
+use eyre::{OptionExt, Report};
+use std::{fmt::Display, io::BufRead};
+use utils::{order_rules::OrderRules, solution::Solution, trace};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (OrderRules<u64>, Vec<Vec<u64>>);
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse(input.as_bytes())
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Result<impl Display, Report> {
+        Ok(sum_correct_middle_pages(rules, updates))
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Result<impl Display, Report> {
+        sort_sum_incorrect(rules, updates)
+    }
+}
+
+fn parse(input: impl BufRead) -> Result<(OrderRules<u64>, Vec<Vec<u64>>), Report> {
+    let mut lines = input.lines();
+
+    let mut rules = OrderRules::new();
+    for line in &mut lines {
+        let line = line?;
+        if line.is_empty() {
+            break;
+        }
+        let (before, after) = line.split_once('|').ok_or_eyre("Missing '|'")?;
+        rules.add(before.parse()?, after.parse()?);
+    }
+
+    let updates = lines
+        .map(|line| {
+            let line = line?;
+            line.split(',').map(|page| Ok(page.parse()?)).collect()
+        })
+        .collect::<Result<_, Report>>()?;
+
+    Ok((rules, updates))
+}
+
+/// Filters the updates to those which are correct according to the given ordering rules, then sums
+/// their middle page numbers.
+fn sum_correct_middle_pages(rules: &OrderRules<u64>, updates: &[Vec<u64>]) -> u64 {
+    updates
+        .iter()
+        .filter(|update| is_order_correct(rules, update))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+/// Returns whether this update is in the correct order according to the given ordering rules.
+fn is_order_correct(rules: &OrderRules<u64>, update: &[u64]) -> bool {
+    match rules.check(update) {
+        Ok(()) => true,
+        Err(violation) => {
+            trace!("{:?} is incorrect: {}", update, violation);
+            false
+        }
+    }
+}
+
+/// Filters the updates to those which are not correct, sorts them to be correct, then sums their
+/// middle page numbers.
+fn sort_sum_incorrect(rules: &OrderRules<u64>, updates: &[Vec<u64>]) -> Result<u64, Report> {
+    updates
+        .iter()
+        .filter(|update| !is_order_correct(rules, update))
+        .map(|update| Ok(rules.sort(update)?[update.len() / 2]))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day5, example);
+
+    const EXAMPLE_RULES: &[(u64, u64)] = &[
+        (47, 53),
+        (97, 13),
+        (97, 61),
+        (97, 47),
+        (75, 29),
+        (61, 13),
+        (75, 53),
+        (29, 13),
+        (97, 29),
+        (53, 29),
+        (61, 53),
+        (97, 53),
+        (61, 29),
+        (47, 13),
+        (75, 47),
+        (97, 75),
+        (47, 61),
+        (75, 61),
+        (47, 29),
+        (75, 13),
+        (53, 13),
+    ];
+
+    fn example_rules() -> OrderRules<u64> {
+        EXAMPLE_RULES.iter().copied().collect()
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(parse("".as_bytes()).unwrap(), (OrderRules::new(), vec![]));
+    }
+
+    #[test]
+    fn parse_small() {
+        assert_eq!(
+            parse("12|43\n42|66\n\n1,2,3\n4,5\n6".as_bytes()).unwrap(),
+            (
+                [(12, 43), (42, 66)].into_iter().collect(),
+                vec![vec![1, 2, 3], vec![4, 5], vec![6]]
+            )
+        );
+    }
+
+    #[test]
+    fn correct_orderings() {
+        assert!(is_order_correct(&example_rules(), &[75, 47, 61, 53, 29]));
+        assert!(is_order_correct(&example_rules(), &[97, 61, 53, 29, 13]));
+        assert!(is_order_correct(&example_rules(), &[75, 29, 13]));
+        assert!(!is_order_correct(&example_rules(), &[75, 97, 47, 61, 53]));
+        assert!(!is_order_correct(&example_rules(), &[61, 13, 29]));
+        assert!(!is_order_correct(&example_rules(), &[97, 13, 75, 29, 47]));
+    }
+
+    #[test]
+    fn sum_correct_example() {
+        assert_eq!(
+            sum_correct_middle_pages(
+                &example_rules(),
+                &[
+                    vec![75, 47, 61, 53, 29],
+                    vec![97, 61, 53, 29, 13],
+                    vec![75, 29, 13],
+                    vec![75, 97, 47, 61, 53],
+                    vec![61, 13, 29],
+                    vec![97, 13, 75, 29, 47],
+                ]
+            ),
+            143
+        );
+    }
+
+    #[test]
+    fn first_violation() {
+        assert_eq!(
+            example_rules()
+                .check(&[97, 13, 75, 29, 47])
+                .unwrap_err()
+                .to_string(),
+            "75 must come before 13, but is at index 2 after it at 1"
+        );
+    }
+
+    #[test]
+    fn sort_example() {
+        let rules = example_rules();
+        assert_eq!(
+            rules.sort(&[75, 97, 47, 61, 53]).unwrap(),
+            vec![97, 75, 47, 61, 53]
+        );
+        assert_eq!(rules.sort(&[61, 13, 29]).unwrap(), vec![61, 29, 13]);
+        assert_eq!(
+            rules.sort(&[97, 13, 75, 29, 47]).unwrap(),
+            vec![97, 75, 47, 29, 13]
+        );
+    }
+
+    #[test]
+    fn sum_incorrect_example() {
+        assert_eq!(
+            sort_sum_incorrect(
+                &example_rules(),
+                &[
+                    vec![75, 47, 61, 53, 29],
+                    vec![97, 61, 53, 29, 13],
+                    vec![75, 29, 13],
+                    vec![75, 97, 47, 61, 53],
+                    vec![61, 13, 29],
+                    vec![97, 13, 75, 29, 47],
+                ]
+            )
+            .unwrap(),
+            123
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use eyre::{eyre, OptionExt, Report};
+use std::{collections::HashSet, fmt::Display, io::BufRead};
+use utils::{grid::Grid, parse::ParseError, parse_chargrid, solution::Solution, Direction};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = State;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        State::parse(input.as_bytes())
+    }
+
+    fn part1(initial_state: &Self::Input) -> Result<impl Display, Report> {
+        Ok(count_visited_positions(initial_state.clone()))
+    }
+
+    fn part2(initial_state: &Self::Input) -> Result<impl Display, Report> {
+        Ok(count_looping_obstacles(initial_state.clone()))
+    }
+}
+
+/// Finds all positions which the guard will visit before leaving.
+fn find_visited_positions(mut state: State) -> Grid<bool> {
+    let mut visited = Grid::new(state.width, state.height);
+    *visited
+        .get_mut(state.guard_position.0, state.guard_position.1)
+        .unwrap() = true;
+    while state.step_guard() {
+        *visited
+            .get_mut(state.guard_position.0, state.guard_position.1)
+            .unwrap() = true;
+    }
+    visited
+}
+
+fn count_visited_positions(initial_state: State) -> usize {
+    let visited_positions = find_visited_positions(initial_state);
+    visited_positions
+        .elements()
+        .map(|(_, _, e)| usize::from(*e))
+        .sum()
+}
+
+/// Checks whether the given state will result in the guard walking round in circles.
+fn will_loop(mut state: State) -> bool {
+    let mut guard_states = HashSet::new();
+    while state.step_guard() {
+        let guard_state = (state.guard_position, state.guard_direction);
+        if guard_states.contains(&guard_state) {
+            return true;
+        }
+        guard_states.insert(guard_state);
+    }
+    false
+}
+
+/// Returns the number of positions in which a single obstactle could be placed to make the guard
+/// loop.
+fn count_looping_obstacles(initial_state: State) -> usize {
+    // Find candidate positions by checking where the guard will visit without obstactles.
+    let candidates = find_visited_positions(initial_state.clone())
+        .elements()
+        .filter_map(|(x, y, visited)| if *visited { Some((x, y)) } else { None })
+        .collect::<Vec<_>>();
+
+    // Check which will actually result in loops.
+    candidates
+        .into_iter()
+        .filter(|new_obstruction| {
+            let mut state = initial_state.clone();
+            state.obstructions.push(*new_obstruction);
+            will_loop(state)
+        })
+        .count()
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct State {
+    width: usize,
+    height: usize,
+    /// The (x, y) co-ordinates of all obstructions.
+    obstructions: Vec<(usize, usize)>,
+    /// The (x, y) co-ordinate of the guard.
+    guard_position: (usize, usize),
+    guard_direction: Direction,
+}
+
+impl State {
+    fn parse(input: impl BufRead) -> Result<Self, Report> {
+        let grid = parse_chargrid(input)?;
+
+        let mut obstructions = Vec::new();
+        let mut guard_position = None;
+        let mut guard_direction = Direction::Up;
+        for (x, y, c) in grid.elements() {
+            match c {
+                '#' => {
+                    obstructions.push((x, y));
+                }
+                '>' | '<' | '^' | 'v' if guard_position.is_some() => {
+                    return Err(
+                        eyre!("Found two guards.").wrap_err(ParseError::in_grid(&grid, x, y))
+                    );
+                }
+                '>' => {
+                    guard_direction = Direction::Right;
+                    guard_position = Some((x, y));
+                }
+                '<' => {
+                    guard_direction = Direction::Left;
+                    guard_position = Some((x, y));
+                }
+                '^' => {
+                    guard_direction = Direction::Up;
+                    guard_position = Some((x, y));
+                }
+                'v' => {
+                    guard_direction = Direction::Down;
+                    guard_position = Some((x, y));
+                }
+                '.' => {}
+                _ => {
+                    return Err(eyre!("Unexpected character in input: '{}'", c)
+                        .wrap_err(ParseError::in_grid(&grid, x, y)));
+                }
+            }
+        }
+
+        Ok(Self {
+            width: grid.width(),
+            height: grid.height(),
+            obstructions,
+            guard_position: guard_position.ok_or_eyre("No guard")?,
+            guard_direction,
+        })
+    }
+
+    /// Move the guard one step, or return false if the guard would move out of bounds.
+    fn step_guard(&mut self) -> bool {
+        let Some(next_guard_position) =
+            self.guard_direction
+                .move_from(self.guard_position, self.width, self.height)
+        else {
+            return false;
+        };
+        if self.obstructions.contains(&next_guard_position) {
+            self.guard_direction = self.guard_direction.rotate_clockwise();
+        } else {
+            self.guard_position = next_guard_position;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day6, example);
+
+    #[test]
+    fn parse_example() {
+        let parsed = State::parse(include_str!("../examples/example.txt").as_bytes()).unwrap();
+        assert_eq!(
+            parsed,
+            State {
+                width: 10,
+                height: 10,
+                guard_position: (4, 6),
+                guard_direction: Direction::Up,
+                obstructions: vec![
+                    (4, 0),
+                    (9, 1),
+                    (2, 3),
+                    (7, 4),
+                    (1, 6),
+                    (8, 7),
+                    (0, 8),
+                    (6, 9),
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn parse_crlf() {
+        assert_eq!(
+            State::parse("..#\r\n.^.\r\n...\r\n".as_bytes()).unwrap(),
+            State::parse("..#\n.^.\n...\n".as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_unexpected_character() {
+        let error = State::parse("..#\n.^.\n.x.\n".as_bytes()).unwrap_err();
+        let location = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(location.line(), 3);
+        assert_eq!(location.column(), Some(2));
+    }
+
+    #[test]
+    fn count_visited_example() {
+        let initial_state =
+            State::parse(include_str!("../examples/example.txt").as_bytes()).unwrap();
+        assert_eq!(count_visited_positions(initial_state), 41);
+    }
+
+    #[test]
+    fn example_loops() {
+        assert!(!will_loop(
+            State::parse(include_str!("../examples/example.txt").as_bytes()).unwrap()
+        ));
+        assert!(will_loop(
+            State::parse(
+                "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#.#^.....
+........#.
+#.........
+......#...
+"
+                .as_bytes()
+            )
+            .unwrap()
+        ));
+        assert!(will_loop(
+            State::parse(
+                "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+......#.#.
+#.........
+......#...
+"
+                .as_bytes()
+            )
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    fn example_loop_count() {
+        let initial_state =
+            State::parse(include_str!("../examples/example.txt").as_bytes()).unwrap();
+        assert_eq!(count_looping_obstacles(initial_state), 6);
+    }
+}
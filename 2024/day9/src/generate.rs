@@ -0,0 +1,81 @@
+//! Generates synthetic disk maps.
+
+use utils::random::Rng;
+
+/// Generates a disk map with the given number of files, which should be at least one.
+///
+/// As in the puzzle input each file is between 1 and 9 blocks long, with up to 9 blocks of free
+/// space between files.
+pub fn generate(rng: &mut Rng, files: usize) -> String {
+    let mut map = String::with_capacity(files * 2);
+    for file in 0..files {
+        if file > 0 {
+            map.push(digit(rng.range(0..10)));
+        }
+        map.push(digit(rng.range(1..10)));
+    }
+    map.push('\n');
+    map
+}
+
+fn digit(value: u64) -> char {
+    char::from_digit(value as u32, 10).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{checksum, compact, compact_no_fragmentation, lengths_to_blocks, parse_digits};
+
+    /// Compacts the disk by moving whole files, working with spans rather than blocks.
+    fn compact_spans(lengths: &[usize]) -> usize {
+        // (start, length) for each file, and each free space.
+        let mut files = Vec::new();
+        let mut free = Vec::new();
+        let mut position = 0;
+        for (i, &length) in lengths.iter().enumerate() {
+            if i % 2 == 0 {
+                files.push((position, length));
+            } else {
+                free.push((position, length));
+            }
+            position += length;
+        }
+        for file in files.iter_mut().rev() {
+            if let Some(space) = free
+                .iter_mut()
+                .find(|space| space.0 < file.0 && space.1 >= file.1)
+            {
+                file.0 = space.0;
+                space.0 += file.1;
+                space.1 -= file.1;
+            }
+        }
+        files
+            .iter()
+            .enumerate()
+            .map(|(id, &(start, length))| (start..start + length).sum::<usize>() * id)
+            .sum()
+    }
+
+    #[test]
+    fn generated_disk_maps() {
+        let mut rng = Rng::new(9);
+        for files in [1, 2, 10, 100] {
+            let map = generate(&mut rng, files);
+            let lengths = parse_digits(map.trim()).unwrap();
+            assert_eq!(lengths.len(), files * 2 - 1);
+            let original = lengths_to_blocks(&lengths);
+
+            let mut blocks = original.clone();
+            compact(&mut blocks);
+            let used = blocks.iter().filter(|block| block.is_some()).count();
+            assert!(blocks[..used].iter().all(Option::is_some));
+            assert_eq!(used, lengths.iter().step_by(2).sum::<usize>());
+
+            let mut blocks = original;
+            compact_no_fragmentation(&mut blocks);
+            assert_eq!(checksum(&blocks), compact_spans(&lengths));
+        }
+    }
+}
@@ -0,0 +1,370 @@
+use eyre::{Report, bail, eyre};
+use std::{cmp::min, fmt::Display, io::BufRead, str::FromStr};
+use utils::{budget::progress, parse::parse_lines, solution::Solution, trace};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse(input.as_bytes())
+    }
+
+    fn part1(machines: &Self::Input) -> Result<impl Display, Report> {
+        find_min_presses(machines, |machine| Ok(machine.min_light_presses()))
+    }
+
+    fn part2(machines: &Self::Input) -> Result<impl Display, Report> {
+        find_min_presses(machines, Machine::min_joltage_presses)
+    }
+}
+
+fn parse(input: impl BufRead) -> Result<Vec<Machine>, Report> {
+    parse_lines(input, str::parse)
+}
+
+fn find_min_presses(
+    machines: &[Machine],
+    min_presses: fn(&Machine) -> Result<u32, Report>,
+) -> Result<u32, Report> {
+    machines.iter().map(min_presses).sum()
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Machine {
+    /// A bitset, 1 means on.
+    lights: u64,
+    /// A 1 bit for a button means it toggles that light.
+    buttons: Vec<u64>,
+    joltages: Vec<u64>,
+}
+
+impl Machine {
+    fn min_light_presses(&self) -> u32 {
+        let mut min_presses = u32::MAX;
+        // Try pressing all possible combinations of buttons.
+        for combination in 0u64..(1 << self.buttons.len()) {
+            let mut light_result = 0;
+            for (i, button) in self.buttons.iter().enumerate() {
+                if combination & (1 << i) != 0 {
+                    light_result ^= button;
+                }
+            }
+            if light_result == self.lights {
+                min_presses = min(min_presses, combination.count_ones());
+            }
+        }
+        min_presses
+    }
+
+    fn min_joltage_presses(&self) -> Result<u32, Report> {
+        for presses in 0..=self
+            .joltages
+            .iter()
+            .copied()
+            .sum::<u64>()
+            .try_into()
+            .unwrap()
+        {
+            trace!("Trying {presses} presses");
+            if self.can_make_joltage_with_presses(presses, &mut vec![0; self.joltages.len()])? {
+                return Ok(presses);
+            }
+        }
+        Ok(u32::MAX)
+    }
+
+    // Returns whether it is possible to make the desired joltages with no more than the given
+    // number of button presses, starting from the given joltages.
+    fn can_make_joltage_with_presses(
+        &self,
+        max_presses: u32,
+        counters: &mut [u64],
+    ) -> Result<bool, Report> {
+        progress()?;
+        Ok(if counters == self.joltages {
+            true
+        } else if max_presses == 0
+            || counters
+                .iter()
+                .enumerate()
+                .any(|(i, &counter)| counter > self.joltages[i])
+        {
+            false
+        } else {
+            for button in &self.buttons {
+                for (bit, counter) in counters.iter_mut().enumerate().take(size_of::<u64>()) {
+                    if button & (1 << bit) != 0 {
+                        *counter += 1;
+                    }
+                }
+                if self.can_make_joltage_with_presses(max_presses - 1, counters)? {
+                    return Ok(true);
+                }
+                for (bit, counter) in counters.iter_mut().enumerate().take(size_of::<u64>()) {
+                    if button & (1 << bit) != 0 {
+                        *counter -= 1;
+                    }
+                }
+            }
+            false
+        })
+    }
+}
+
+impl FromStr for Machine {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((lights_str, rest)) = s.split_once("] (") else {
+            bail!("Missing end of lights");
+        };
+        if !lights_str.starts_with('[') {
+            bail!("Missing start of lights");
+        }
+        if lights_str[1..].chars().count() > u64::BITS as usize {
+            bail!("Too many lights");
+        }
+
+        let mut lights = 0;
+        for c in lights_str[1..].chars().rev() {
+            lights <<= 1;
+            match c {
+                '#' => lights |= 1,
+                '.' => {}
+                _ => bail!("Unexpected light character '{c}'"),
+            }
+        }
+
+        let Some((buttons, joltages)) = rest.split_once(") {") else {
+            bail!("Missing start of joltages");
+        };
+        let buttons = buttons
+            .split(") (")
+            .map(|button| {
+                button.split(',').try_fold(0, |lights, light| {
+                    let light = light.parse::<u32>()?;
+                    let bit = 1u64
+                        .checked_shl(light)
+                        .ok_or_else(|| eyre!("Light {light} out of range"))?;
+                    Ok::<_, Report>(lights | bit)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let joltages = joltages
+            .trim_end_matches('}')
+            .split(',')
+            .map(|joltage| joltage.parse())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            lights,
+            buttons,
+            joltages,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::{
+        budget::{Budget, Timeout, with_budget},
+        example_tests,
+    };
+
+    example_tests!(Day10, example);
+
+    #[test]
+    fn parse_example() {
+        assert_eq!(
+            parse(include_str!("../examples/example.txt").as_bytes()).unwrap(),
+            vec![
+                Machine {
+                    lights: 0b0110,
+                    buttons: vec![0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011],
+                    joltages: vec![3, 5, 4, 7],
+                },
+                Machine {
+                    lights: 0b01000,
+                    buttons: vec![0b11101, 0b01100, 0b10001, 0b00111, 0b11110],
+                    joltages: vec![7, 5, 12, 7, 2],
+                },
+                Machine {
+                    lights: 0b101110,
+                    buttons: vec![0b011111, 0b011001, 0b110111, 0b000110],
+                    joltages: vec![10, 11, 11, 5, 10, 5],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_invalid_machines() {
+        for machine in [
+            "[.] () {}",
+            "[.] (x) {1}",
+            "[.] (64) {1}",
+            "[.#] (0,) {1}",
+            "[.] (0) {x}",
+            "(0) {1}",
+        ] {
+            assert!(machine.parse::<Machine>().is_err(), "{}", machine);
+        }
+        assert!(
+            format!("[{}] (0) {{1}}", ".".repeat(65))
+                .parse::<Machine>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn example_min_light_presses() {
+        let machine1 = Machine {
+            lights: 0b0110,
+            buttons: vec![0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011],
+            joltages: vec![3, 5, 4, 7],
+        };
+        let machine2 = Machine {
+            lights: 0b01000,
+            buttons: vec![0b11101, 0b01100, 0b10001, 0b00111, 0b11110],
+            joltages: vec![7, 5, 12, 7, 2],
+        };
+        let machine3 = Machine {
+            lights: 0b101110,
+            buttons: vec![0b011111, 0b011001, 0b110111, 0b000110],
+            joltages: vec![10, 11, 11, 5, 10, 5],
+        };
+        assert_eq!(machine1.min_light_presses(), 2);
+        assert_eq!(machine2.min_light_presses(), 3);
+        assert_eq!(machine3.min_light_presses(), 2);
+        assert_eq!(
+            find_min_presses(&[machine1, machine2, machine3], |machine| Ok(
+                machine.min_light_presses()
+            ))
+            .unwrap(),
+            7
+        );
+    }
+
+    #[test]
+    fn simple_min_joltage_presses() {
+        assert_eq!(
+            Machine {
+                lights: 0,
+                buttons: vec![0b01, 0b10, 0b11],
+                joltages: vec![0, 0],
+            }
+            .min_joltage_presses()
+            .unwrap(),
+            0
+        );
+        assert_eq!(
+            Machine {
+                lights: 0,
+                buttons: vec![0b01, 0b10, 0b11],
+                joltages: vec![0, 1],
+            }
+            .min_joltage_presses()
+            .unwrap(),
+            1
+        );
+        assert_eq!(
+            Machine {
+                lights: 0,
+                buttons: vec![0b01, 0b10, 0b11],
+                joltages: vec![1, 0],
+            }
+            .min_joltage_presses()
+            .unwrap(),
+            1
+        );
+        assert_eq!(
+            Machine {
+                lights: 0,
+                buttons: vec![0b01, 0b10, 0b11],
+                joltages: vec![1, 1],
+            }
+            .min_joltage_presses()
+            .unwrap(),
+            1
+        );
+        assert_eq!(
+            Machine {
+                lights: 0,
+                buttons: vec![0b01],
+                joltages: vec![1, 0],
+            }
+            .min_joltage_presses()
+            .unwrap(),
+            1
+        );
+        assert_eq!(
+            Machine {
+                lights: 0,
+                buttons: vec![0b01],
+                joltages: vec![2, 0],
+            }
+            .min_joltage_presses()
+            .unwrap(),
+            2
+        );
+        assert_eq!(
+            Machine {
+                lights: 0,
+                buttons: vec![0b01, 0b10],
+                joltages: vec![1, 1],
+            }
+            .min_joltage_presses()
+            .unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn example_min_joltage_presses() {
+        let machine1 = Machine {
+            lights: 0b0110,
+            buttons: vec![0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011],
+            joltages: vec![3, 5, 4, 7],
+        };
+        let machine2 = Machine {
+            lights: 0b01000,
+            buttons: vec![0b11101, 0b01100, 0b10001, 0b00111, 0b11110],
+            joltages: vec![7, 5, 12, 7, 2],
+        };
+        let machine3 = Machine {
+            lights: 0b101110,
+            buttons: vec![0b011111, 0b011001, 0b110111, 0b000110],
+            joltages: vec![10, 11, 11, 5, 10, 5],
+        };
+        assert_eq!(machine1.min_joltage_presses().unwrap(), 10);
+        assert_eq!(machine2.min_joltage_presses().unwrap(), 12);
+        assert_eq!(machine3.min_joltage_presses().unwrap(), 11);
+        assert_eq!(
+            find_min_presses(
+                &[machine1, machine2, machine3],
+                Machine::min_joltage_presses
+            )
+            .unwrap(),
+            33
+        );
+    }
+
+    #[test]
+    fn joltage_presses_timeout() {
+        let machine = Machine {
+            lights: 0b01000,
+            buttons: vec![0b11101, 0b01100, 0b10001, 0b00111, 0b11110],
+            joltages: vec![7, 5, 12, 7, 2],
+        };
+        let budget = Budget {
+            time: None,
+            steps: Some(1000),
+        };
+        let e = with_budget(budget, || machine.min_joltage_presses()).unwrap_err();
+        assert!(e.is::<Timeout>());
+    }
+}
@@ -0,0 +1,131 @@
+//! Generates synthetic device graphs.
+
+use std::collections::BTreeSet;
+use utils::random::Rng;
+
+/// The most paths there may be from any device to `out`, so that counting them can't overflow.
+const MAX_PATHS: u64 = 1 << 40;
+
+/// How far ahead in the order of devices their outputs may be, to keep the graph long and thin
+/// like the puzzle input.
+const OUTPUT_WINDOW: usize = 8;
+
+/// Generates a graph of the given number of devices (at least 5, and at most 26³ + 5 as the other
+/// names have three letters), each with up to `max_outputs` outputs.
+///
+/// As in the puzzle input the graph has no cycles, and `svr` and `you` both have paths to `out`.
+/// `svr` also has paths through `fft` and `dac`.
+pub fn generate(rng: &mut Rng, devices: usize, max_outputs: usize) -> String {
+    let names = device_names(rng, devices.max(5));
+    let devices = names.len();
+
+    // Devices only output to later devices, and each outputs to the next so that everything
+    // after `svr` is reachable from it.
+    let mut paths = vec![0; devices];
+    paths[devices - 1] = 1;
+    let mut outputs = vec![Vec::new(); devices];
+    for device in (0..devices - 1).rev() {
+        let window = device + 1..(device + 1 + OUTPUT_WINDOW).min(devices);
+        let mut candidates = window.clone().skip(1).collect::<Vec<_>>();
+        rng.shuffle(&mut candidates);
+        let extra = rng.index(max_outputs.max(1));
+        outputs[device].push(window.start);
+        paths[device] = paths[window.start];
+        for output in candidates.into_iter().take(extra) {
+            if paths[device] + paths[output] <= MAX_PATHS {
+                outputs[device].push(output);
+                paths[device] += paths[output];
+            }
+        }
+        rng.shuffle(&mut outputs[device]);
+    }
+
+    let mut order = (0..devices - 1).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+    order
+        .into_iter()
+        .map(|device| {
+            let outputs = outputs[device]
+                .iter()
+                .map(|&output| names[output].as_str())
+                .collect::<Vec<_>>();
+            format!("{}: {}\n", names[device], outputs.join(" "))
+        })
+        .collect()
+}
+
+/// Returns the names of the devices in the order that they may output to each other: `svr`
+/// first, then `you`, `fft` and `dac` among random names, and `out` last.
+fn device_names(rng: &mut Rng, devices: usize) -> Vec<String> {
+    const SPECIAL: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+    let mut names = BTreeSet::new();
+    while names.len() < devices - SPECIAL.len() {
+        let name = (0..3)
+            .map(|_| char::from(b'a' + rng.range(0..26) as u8))
+            .collect::<String>();
+        if !SPECIAL.contains(&name.as_str()) {
+            names.insert(name);
+        }
+    }
+    let mut names = names.into_iter().collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    let mut positions = [(); 3].map(|_| rng.index(names.len() + 1));
+    positions.sort();
+    for (offset, (position, name)) in positions.into_iter().zip(["you", "fft", "dac"]).enumerate() {
+        names.insert(position + offset, name.to_string());
+    }
+    names.insert(0, "svr".to_string());
+    names.push("out".to_string());
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_paths, parse};
+    use utils::graph::Graph;
+
+    /// Counts paths by walking every one of them.
+    fn walk_paths(connections: &Graph, from: &str, must_visit: &[&str]) -> u64 {
+        let must_visit = must_visit
+            .iter()
+            .copied()
+            .filter(|&device| device != from)
+            .collect::<Vec<_>>();
+        if from == "out" {
+            return u64::from(must_visit.is_empty());
+        }
+        let from = connections.id(from).unwrap();
+        connections
+            .successors(from)
+            .iter()
+            .map(|&output| walk_paths(connections, connections.label(output), &must_visit))
+            .sum()
+    }
+
+    #[test]
+    fn generated_graphs() {
+        let mut rng = Rng::new(11);
+        for (devices, max_outputs) in [(5, 1), (20, 2), (30, 3)] {
+            let connections = parse(generate(&mut rng, devices, max_outputs).as_bytes()).unwrap();
+            assert_eq!(connections.len(), devices);
+            assert!(walk_paths(&connections, "you", &[]) > 0);
+            assert!(walk_paths(&connections, "svr", &["dac", "fft"]) > 0);
+            assert_eq!(
+                count_paths(&connections, "you", &["out"]).unwrap(),
+                walk_paths(&connections, "you", &[])
+            );
+            assert_eq!(
+                count_paths(&connections, "svr", &["dac", "fft", "out"]).unwrap(),
+                walk_paths(&connections, "svr", &["dac", "fft"])
+            );
+        }
+    }
+
+    #[test]
+    fn large_graph() {
+        let mut rng = Rng::new(111);
+        let connections = parse(generate(&mut rng, 10_000, 4).as_bytes()).unwrap();
+        assert!(count_paths(&connections, "svr", &["dac", "fft", "out"]).unwrap() <= MAX_PATHS);
+    }
+}
@@ -0,0 +1,205 @@
+use eyre::{OptionExt, Report, bail};
+use std::{fmt::Display, io::BufRead, iter::once};
+use utils::{graph::Graph, parse::parse_lines, solution::Solution};
+
+pub mod generate;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    /// The devices, with an edge to each of their outputs.
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse(input.as_bytes())
+    }
+
+    fn part1(connections: &Self::Input) -> Result<impl Display, Report> {
+        count_paths(connections, "you", &["out"])
+    }
+
+    fn part2(connections: &Self::Input) -> Result<impl Display, Report> {
+        count_paths(connections, "svr", &["dac", "fft", "out"])
+    }
+}
+
+fn parse(input: impl BufRead) -> Result<Graph, Report> {
+    Ok(Graph::from_adjacency(parse_lines(input, |line| {
+        let Some((device, outputs)) = line.split_once(": ") else {
+            bail!("Missing colon");
+        };
+        let outputs = outputs
+            .split(' ')
+            .map(ToOwned::to_owned)
+            .collect::<Vec<_>>();
+        Ok((device.to_owned(), outputs))
+    })?))
+}
+
+/// Returns the number of paths from `from` to the last of `must_visit` which visit all of
+/// `must_visit`, or an error if a cycle allows infinitely many.
+///
+/// Without cycles each path visits the rest of `must_visit` in some order, so this sums over the
+/// orders the product of the number of paths between each consecutive pair.
+fn count_paths(graph: &Graph, from: &str, must_visit: &[&str]) -> Result<u64, Report> {
+    let Some((to, via)) = must_visit.split_last() else {
+        bail!("No devices to visit");
+    };
+    let mut total = 0u64;
+    for order in orders(via) {
+        let mut count = 1u64;
+        for (start, end) in once(&from).chain(&order).zip(order.iter().chain(once(to))) {
+            let (Some(start), Some(end)) = (graph.id(start), graph.id(end)) else {
+                return Ok(0);
+            };
+            count = count
+                .checked_mul(graph.count_paths(start, end)?)
+                .ok_or_eyre("Too many paths")?;
+        }
+        total = total.checked_add(count).ok_or_eyre("Too many paths")?;
+    }
+    Ok(total)
+}
+
+/// Returns every order of the given devices.
+fn orders<'a>(devices: &[&'a str]) -> Vec<Vec<&'a str>> {
+    if devices.is_empty() {
+        return vec![Vec::new()];
+    }
+    (0..devices.len())
+        .flat_map(|first| {
+            let mut rest = devices.to_vec();
+            let first = rest.remove(first);
+            orders(&rest).into_iter().map(move |mut order| {
+                order.insert(0, first);
+                order
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day11, example, example2);
+
+    #[test]
+    fn parse_example() {
+        assert_eq!(
+            parse(include_str!("../examples/example.txt").as_bytes()).unwrap(),
+            Graph::from_adjacency([
+                (
+                    "aaa".to_string(),
+                    vec!["you".to_string(), "hhh".to_string()]
+                ),
+                (
+                    "you".to_string(),
+                    vec!["bbb".to_string(), "ccc".to_string()]
+                ),
+                (
+                    "bbb".to_string(),
+                    vec!["ddd".to_string(), "eee".to_string()]
+                ),
+                (
+                    "ccc".to_string(),
+                    vec!["ddd".to_string(), "eee".to_string(), "fff".to_string()]
+                ),
+                ("ddd".to_string(), vec!["ggg".to_string()]),
+                ("eee".to_string(), vec!["out".to_string()]),
+                ("fff".to_string(), vec!["out".to_string()]),
+                ("ggg".to_string(), vec!["out".to_string()]),
+                (
+                    "hhh".to_string(),
+                    vec!["ccc".to_string(), "fff".to_string(), "iii".to_string()]
+                ),
+                ("iii".to_string(), vec!["out".to_string()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn count_example_paths_you_to_out() {
+        assert_eq!(
+            count_paths(
+                &Graph::from_adjacency([
+                    (
+                        "aaa".to_string(),
+                        vec!["you".to_string(), "hhh".to_string()]
+                    ),
+                    (
+                        "you".to_string(),
+                        vec!["bbb".to_string(), "ccc".to_string()]
+                    ),
+                    (
+                        "bbb".to_string(),
+                        vec!["ddd".to_string(), "eee".to_string()]
+                    ),
+                    (
+                        "ccc".to_string(),
+                        vec!["ddd".to_string(), "eee".to_string(), "fff".to_string()]
+                    ),
+                    ("ddd".to_string(), vec!["ggg".to_string()]),
+                    ("eee".to_string(), vec!["out".to_string()]),
+                    ("fff".to_string(), vec!["out".to_string()]),
+                    ("ggg".to_string(), vec!["out".to_string()]),
+                    (
+                        "hhh".to_string(),
+                        vec!["ccc".to_string(), "fff".to_string(), "iii".to_string()]
+                    ),
+                    ("iii".to_string(), vec!["out".to_string()]),
+                ]),
+                "you",
+                &["out"],
+            )
+            .unwrap(),
+            5
+        );
+    }
+
+    #[test]
+    fn count_example_paths_svr_to_out() {
+        assert_eq!(
+            count_paths(
+                &parse(include_str!("../examples/example2.txt").as_bytes()).unwrap(),
+                "svr",
+                &["dac", "fft", "out"],
+            )
+            .unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        let connections = parse("you: aaa\naaa: bbb out\nbbb: aaa\n".as_bytes()).unwrap();
+        assert_eq!(
+            count_paths(&connections, "you", &["out"])
+                .unwrap_err()
+                .to_string(),
+            "Cycle in graph: aaa -> bbb -> aaa"
+        );
+        // Loops which can't reach the end don't matter.
+        let connections = parse("you: aaa out\naaa: bbb\nbbb: aaa\n".as_bytes()).unwrap();
+        assert_eq!(count_paths(&connections, "you", &["out"]).unwrap(), 1);
+        assert_eq!(count_paths(&connections, "bbb", &["out"]).unwrap(), 0);
+        assert_eq!(count_paths(&connections, "you", &["you"]).unwrap(), 1);
+    }
+
+    #[test]
+    fn orders_of_devices() {
+        assert_eq!(orders(&[]), [Vec::<&str>::new()]);
+        assert_eq!(
+            orders(&["a", "b", "c"]),
+            [
+                ["a", "b", "c"],
+                ["a", "c", "b"],
+                ["b", "a", "c"],
+                ["b", "c", "a"],
+                ["c", "a", "b"],
+                ["c", "b", "a"],
+            ]
+        );
+    }
+}
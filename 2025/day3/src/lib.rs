@@ -0,0 +1,156 @@
+use eyre::{Report, eyre};
+use std::{fmt::Display, io::BufRead};
+use utils::{parse::parse_lines, solution::Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse(input.as_bytes())
+    }
+
+    fn part1(banks: &Self::Input) -> Result<impl Display, Report> {
+        Ok(total_max_joltage(banks, 2))
+    }
+
+    fn part2(banks: &Self::Input) -> Result<impl Display, Report> {
+        Ok(total_max_joltage(banks, 12))
+    }
+}
+
+fn parse(input: impl BufRead) -> Result<Vec<Vec<u32>>, Report> {
+    parse_lines(input, |line| {
+        line.chars()
+            .map(|c| c.to_digit(10).ok_or_else(|| eyre!("Invalid digit '{c}'")))
+            .collect()
+    })
+}
+
+fn total_max_joltage(banks: &[Vec<u32>], cell_count: usize) -> u64 {
+    banks.iter().map(|bank| max_joltage(bank, cell_count)).sum()
+}
+
+/// Returns the maximum joltage for a single bank.
+fn max_joltage(mut bank: &[u32], cell_count: usize) -> u64 {
+    if bank.len() < cell_count {
+        return 0;
+    }
+
+    let mut total = 0;
+    for remaining_digits in (0..cell_count).rev() {
+        // Leave space for the remaining digits.
+        let next_index = first_max_index(&bank[0..bank.len() - remaining_digits]).unwrap();
+        total = total * 10 + u64::from(bank[next_index]);
+        bank = &bank[next_index + 1..];
+    }
+
+    total
+}
+
+/// Returns the index of the first value which is equal to the maximum.
+fn first_max_index(bank: &[u32]) -> Option<usize> {
+    let mut max_so_far = 0;
+    let mut max_index = None;
+    for (i, &value) in bank.iter().enumerate() {
+        if value > max_so_far {
+            max_index = Some(i);
+            max_so_far = value;
+        }
+    }
+    max_index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day3, example);
+
+    #[test]
+    fn parse_example() {
+        assert_eq!(
+            parse(include_str!("../examples/example.txt").as_bytes()).unwrap(),
+            vec![
+                vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+                vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+                vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+                vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+            ]
+        )
+    }
+
+    #[test]
+    fn example_max_joltage_2() {
+        assert_eq!(
+            max_joltage(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1], 2),
+            98
+        );
+        assert_eq!(
+            max_joltage(&[8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9], 2),
+            89
+        );
+        assert_eq!(
+            max_joltage(&[2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8], 2),
+            78
+        );
+        assert_eq!(
+            max_joltage(&[8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1], 2),
+            92
+        );
+    }
+
+    #[test]
+    fn example_max_total_joltage_2() {
+        assert_eq!(
+            total_max_joltage(
+                &[
+                    vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+                    vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+                    vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+                    vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+                ],
+                2
+            ),
+            357
+        );
+    }
+
+    #[test]
+    fn example_max_joltage_12() {
+        assert_eq!(
+            max_joltage(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1], 12),
+            987654321111
+        );
+        assert_eq!(
+            max_joltage(&[8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9], 12),
+            811111111119
+        );
+        assert_eq!(
+            max_joltage(&[2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8], 12),
+            434234234278
+        );
+        assert_eq!(
+            max_joltage(&[8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1], 12),
+            888911112111
+        );
+    }
+
+    #[test]
+    fn example_max_total_joltage_12() {
+        assert_eq!(
+            total_max_joltage(
+                &[
+                    vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+                    vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+                    vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+                    vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+                ],
+                12
+            ),
+            3121910778619
+        );
+    }
+}
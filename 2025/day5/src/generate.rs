@@ -0,0 +1,56 @@
+//! Generates synthetic lists of fresh ingredient ID ranges and available ingredients.
+
+use std::fmt::Write;
+use utils::random::Rng;
+
+/// Generates the given number of fresh ranges and available ingredient IDs, all between 1 and
+/// `max_id`.
+///
+/// Ranges are up to twice the average gap between them long, so many of them overlap.
+pub fn generate(rng: &mut Rng, ranges: usize, ingredients: usize, max_id: u64) -> String {
+    let max_length = (2 * max_id / ranges.max(1) as u64).max(1);
+    let mut list = String::new();
+    for _ in 0..ranges {
+        let start = rng.range(1..max_id + 1);
+        let end = (start + rng.range(0..max_length)).min(max_id);
+        writeln!(list, "{}-{}", start, end).unwrap();
+    }
+    list.push('\n');
+    for _ in 0..ingredients {
+        writeln!(list, "{}", rng.range(1..max_id + 1)).unwrap();
+    }
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn generated_ingredients() {
+        let mut rng = Rng::new(5);
+        for (ranges, ingredients, max_id) in [(1, 1, 1), (10, 20, 100), (200, 500, 10_000)] {
+            let list = generate(&mut rng, ranges, ingredients, max_id);
+            let parsed = parse(list.as_bytes()).unwrap();
+            assert_eq!(parsed.fresh_ranges.len(), ranges);
+            assert_eq!(parsed.available_ingredients.len(), ingredients);
+
+            let fresh = parsed
+                .fresh_ranges
+                .iter()
+                .flat_map(|range| range.clone())
+                .collect::<BTreeSet<_>>();
+            assert_eq!(
+                parsed.fresh_count(),
+                parsed
+                    .available_ingredients
+                    .iter()
+                    .filter(|ingredient| fresh.contains(ingredient))
+                    .count()
+            );
+            assert_eq!(parsed.fresh_ranges_size(), fresh.len() as u128);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use eyre::{Report, eyre};
+use std::{fmt::Display, io::BufRead, ops::RangeInclusive};
+use utils::{range_set::RangeSet, solution::Solution, verbose};
+
+pub mod generate;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Ingredients;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse(input.as_bytes())
+    }
+
+    fn part1(ingredients: &Self::Input) -> Result<impl Display, Report> {
+        Ok(ingredients.fresh_count())
+    }
+
+    fn part2(ingredients: &Self::Input) -> Result<impl Display, Report> {
+        Ok(ingredients.fresh_ranges_size())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ingredients {
+    fresh_ranges: Vec<RangeInclusive<u64>>,
+    available_ingredients: Vec<u64>,
+}
+
+impl Ingredients {
+    /// Returns the set of ingredients covered by at least one fresh range.
+    fn fresh_set(&self) -> RangeSet<u64> {
+        self.fresh_ranges.iter().cloned().collect()
+    }
+
+    /// Returns the number of available ingredients that are within at least one fresh range.
+    fn fresh_count(&self) -> usize {
+        let fresh = self.fresh_set();
+        self.available_ingredients
+            .iter()
+            .filter(|&&ingredient| fresh.contains(ingredient))
+            .count()
+    }
+
+    /// Returns the total number of ingredients covered by the fresh ranges.
+    fn fresh_ranges_size(&self) -> u128 {
+        let fresh = self.fresh_set();
+        verbose!(
+            "Merged {} ranges into {} ranges",
+            self.fresh_ranges.len(),
+            fresh.range_count()
+        );
+        fresh.total_len()
+    }
+}
+
+fn parse_range(line: &str) -> Result<RangeInclusive<u64>, Report> {
+    let (start, end) = line
+        .split_once('-')
+        .ok_or_else(|| eyre!("Invalid range {line}"))?;
+    Ok(start.parse()?..=end.parse()?)
+}
+
+fn parse(input: impl BufRead) -> Result<Ingredients, Report> {
+    let mut lines = input.lines();
+    let fresh_ranges = (&mut lines)
+        .map_while(|line| match line {
+            Err(e) => Some(Err(e.into())),
+            Ok(line) => {
+                let line = line.trim();
+                if line.is_empty() {
+                    None
+                } else {
+                    Some(parse_range(line))
+                }
+            }
+        })
+        .collect::<Result<_, Report>>()?;
+
+    let available_ingredients = lines
+        .map(|line| Ok(line?.parse::<u64>()?))
+        .collect::<Result<_, Report>>()?;
+
+    Ok(Ingredients {
+        fresh_ranges,
+        available_ingredients,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day5, example);
+
+    #[test]
+    fn parse_example() {
+        assert_eq!(
+            parse(include_str!("../examples/example.txt").as_bytes()).unwrap(),
+            Ingredients {
+                fresh_ranges: vec![3..=5, 10..=14, 16..=20, 12..=18],
+                available_ingredients: vec![1, 5, 8, 11, 17, 32]
+            }
+        )
+    }
+
+    #[test]
+    fn fresh_count_example() {
+        let ingredients = Ingredients {
+            fresh_ranges: vec![3..=5, 10..=14, 16..=20, 12..=18],
+            available_ingredients: vec![1, 5, 8, 11, 17, 32],
+        };
+        assert_eq!(ingredients.fresh_count(), 3);
+    }
+
+    #[test]
+    fn fresh_range_size_example() {
+        let ingredients = Ingredients {
+            fresh_ranges: vec![3..=5, 10..=14, 16..=20, 12..=18],
+            available_ingredients: vec![1, 5, 8, 11, 17, 32],
+        };
+        assert_eq!(ingredients.fresh_ranges_size(), 14);
+    }
+
+    #[test]
+    fn fresh_adjacent_and_nested() {
+        let ingredients = Ingredients {
+            fresh_ranges: vec![0..=1, 1..=2, 3..=4, 10..=15, 11..=12],
+            available_ingredients: vec![0, 4, 5, 9, 12],
+        };
+        assert_eq!(ingredients.fresh_set().range_count(), 2);
+        assert_eq!(ingredients.fresh_count(), 3);
+        assert_eq!(ingredients.fresh_ranges_size(), 11);
+    }
+}
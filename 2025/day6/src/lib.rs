@@ -0,0 +1,210 @@
+use eyre::{OptionExt, Report, eyre};
+use std::{fmt::Display, io::BufRead, str::FromStr};
+use utils::{
+    grid::Grid,
+    parse::{ColumnOrder, column_numbers, parse_column_blocks, row_numbers},
+    solution::Solution,
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The worksheet parsed as for part 1 and part 2.
+    type Input = (Vec<Problem>, Vec<Problem>);
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        Ok((parse1(input.as_bytes())?, parse2(input.as_bytes())?))
+    }
+
+    fn part1((problems, _): &Self::Input) -> Result<impl Display, Report> {
+        Ok(sum_solutions(problems))
+    }
+
+    fn part2((_, problems): &Self::Input) -> Result<impl Display, Report> {
+        Ok(sum_solutions(problems))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Problem {
+    operation: Operation,
+    numbers: Vec<u64>,
+}
+
+impl Problem {
+    fn solution(&self) -> u64 {
+        match self.operation {
+            Operation::Addition => self.numbers.iter().sum(),
+            Operation::Multiplication => self.numbers.iter().product(),
+        }
+    }
+
+    /// Parses a problem from a block of the worksheet, with the operation on the bottom row and the
+    /// numbers above it read with the given function.
+    fn from_block(
+        block: &Grid<char>,
+        read_numbers: impl Fn(&Grid<char>) -> Result<Vec<u64>, Report>,
+    ) -> Result<Self, Report> {
+        let operation_row = block.rows().last().ok_or_eyre("Empty block")?;
+        Ok(Self {
+            operation: operation_row.iter().collect::<String>().trim().parse()?,
+            numbers: read_numbers(&block.subgrid(0, 0, block.width(), block.height() - 1))?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operation {
+    Addition,
+    Multiplication,
+}
+
+impl FromStr for Operation {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Self::Addition),
+            "*" => Ok(Self::Multiplication),
+            _ => Err(eyre!("Invalid operation {s}")),
+        }
+    }
+}
+
+fn parse1(input: impl BufRead) -> Result<Vec<Problem>, Report> {
+    parse_column_blocks(input)?
+        .iter()
+        .map(|block| Problem::from_block(block, row_numbers))
+        .collect()
+}
+
+fn parse2(input: impl BufRead) -> Result<Vec<Problem>, Report> {
+    parse_column_blocks(input)?
+        .iter()
+        .rev()
+        .map(|block| {
+            Problem::from_block(block, |numbers| {
+                column_numbers(numbers, ColumnOrder::RightToLeft)
+            })
+        })
+        .collect()
+}
+
+fn sum_solutions(problems: &[Problem]) -> u64 {
+    problems.iter().map(|problem| problem.solution()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day6, example);
+
+    #[test]
+    fn parse_example_part_1() {
+        assert_eq!(
+            parse1(include_str!("../examples/example.txt").as_bytes()).unwrap(),
+            vec![
+                Problem {
+                    numbers: vec![123, 45, 6],
+                    operation: Operation::Multiplication,
+                },
+                Problem {
+                    numbers: vec![328, 64, 98],
+                    operation: Operation::Addition,
+                },
+                Problem {
+                    numbers: vec![51, 387, 215],
+                    operation: Operation::Multiplication,
+                },
+                Problem {
+                    numbers: vec![64, 23, 314],
+                    operation: Operation::Addition,
+                },
+            ]
+        )
+    }
+
+    #[test]
+    fn parse_example_part_2() {
+        assert_eq!(
+            parse2(include_str!("../examples/example.txt").as_bytes()).unwrap(),
+            vec![
+                Problem {
+                    numbers: vec![4, 431, 623],
+                    operation: Operation::Addition,
+                },
+                Problem {
+                    numbers: vec![175, 581, 32],
+                    operation: Operation::Multiplication,
+                },
+                Problem {
+                    numbers: vec![8, 248, 369],
+                    operation: Operation::Addition,
+                },
+                Problem {
+                    numbers: vec![356, 24, 1],
+                    operation: Operation::Multiplication,
+                },
+            ]
+        )
+    }
+
+    #[test]
+    fn parse_example_without_trailing_spaces() {
+        let input = "123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +\n";
+        assert_eq!(sum_solutions(&parse1(input.as_bytes()).unwrap()), 4277556);
+        assert_eq!(sum_solutions(&parse2(input.as_bytes()).unwrap()), 3263827);
+    }
+
+    #[test]
+    fn sum_example_part_1_problems() {
+        assert_eq!(
+            sum_solutions(&[
+                Problem {
+                    numbers: vec![123, 45, 6],
+                    operation: Operation::Multiplication,
+                },
+                Problem {
+                    numbers: vec![328, 64, 98],
+                    operation: Operation::Addition,
+                },
+                Problem {
+                    numbers: vec![51, 387, 215],
+                    operation: Operation::Multiplication,
+                },
+                Problem {
+                    numbers: vec![64, 23, 314],
+                    operation: Operation::Addition,
+                },
+            ]),
+            4277556
+        );
+    }
+
+    #[test]
+    fn sum_example_part_2_problems() {
+        assert_eq!(
+            sum_solutions(&[
+                Problem {
+                    numbers: vec![4, 431, 623],
+                    operation: Operation::Addition,
+                },
+                Problem {
+                    numbers: vec![175, 581, 32],
+                    operation: Operation::Multiplication,
+                },
+                Problem {
+                    numbers: vec![8, 248, 369],
+                    operation: Operation::Addition,
+                },
+                Problem {
+                    numbers: vec![356, 24, 1],
+                    operation: Operation::Multiplication,
+                },
+            ]),
+            3263827
+        );
+    }
+}
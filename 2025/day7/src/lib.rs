@@ -0,0 +1,71 @@
+use eyre::{OptionExt, Report};
+use std::fmt::Display;
+use utils::{grid::Grid, parse_chargrid, solution::Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        parse_chargrid(input.as_bytes())
+    }
+
+    fn part1(grid: &Self::Input) -> Result<impl Display, Report> {
+        Ok(count_splits_and_timelines(grid)?.0)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<impl Display, Report> {
+        Ok(count_splits_and_timelines(grid)?.1)
+    }
+}
+
+fn count_splits_and_timelines(grid: &Grid<char>) -> Result<(usize, u64), Report> {
+    let mut beams = grid
+        .rows()
+        .next()
+        .ok_or_eyre("Empty grid")?
+        .iter()
+        .map(|&c| if c == 'S' { 1 } else { 0 })
+        .collect::<Vec<u64>>();
+
+    let mut split_count = 0;
+    let width = grid.width();
+    for row in grid.rows().skip(1) {
+        let mut new_beams = vec![0; width];
+        for (x, (&beam, &c)) in beams.iter().zip(row.iter()).enumerate() {
+            if c == '^' {
+                if beam > 0 {
+                    split_count += 1;
+                }
+                if x > 0 {
+                    new_beams[x - 1] += beam;
+                }
+                if x < width - 1 {
+                    new_beams[x + 1] += beam;
+                }
+            } else {
+                new_beams[x] += beam;
+            }
+        }
+
+        beams = new_beams;
+    }
+
+    Ok((split_count, beams.iter().sum()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day7, example);
+
+    #[test]
+    fn example() {
+        let grid = parse_chargrid(include_str!("../examples/example.txt").as_bytes()).unwrap();
+
+        assert_eq!(count_splits_and_timelines(&grid).unwrap(), (21, 40));
+    }
+}
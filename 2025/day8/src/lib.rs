@@ -0,0 +1,210 @@
+use eyre::{Report, bail, eyre};
+use std::{fmt::Display, io::BufRead};
+use utils::{args::Params, disjoint_set::DisjointSet, parse::parse_lines, solution::Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    /// The positions of the junction boxes, and how many connections to make for part 1.
+    type Input = (Vec<[i64; 3]>, usize);
+
+    const PARAMS: &[&str] = &["connections"];
+
+    fn parse(input: &str) -> Result<Self::Input, Report> {
+        Self::parse_with_params(input, &Params::default())
+    }
+
+    fn parse_with_params(input: &str, params: &Params) -> Result<Self::Input, Report> {
+        Ok((parse(input.as_bytes())?, params.get("connections", 1000)?))
+    }
+
+    fn part1((positions, connections): &Self::Input) -> Result<impl Display, Report> {
+        connect_count_multiply(positions, *connections)
+    }
+
+    fn part2((positions, _): &Self::Input) -> Result<impl Display, Report> {
+        part2(positions)
+    }
+}
+
+fn parse(input: impl BufRead) -> Result<Vec<[i64; 3]>, Report> {
+    parse_lines(input, |line| {
+        line.split(',')
+            .map(|part| part.parse())
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|v: Vec<i64>| eyre!("{} numbers on line, expected 3", v.len()))
+    })
+}
+
+/// Make the `connection_count` shortest connections, then count how many junction boxes are in the
+/// three biggest circuits and multiply them.
+fn connect_count_multiply(
+    positions: &[[i64; 3]],
+    connection_count: usize,
+) -> Result<usize, Report> {
+    let distances = find_distances(positions);
+    if connection_count > distances.len() {
+        bail!(
+            "Can't make {} connections between {} junction boxes",
+            connection_count,
+            positions.len()
+        );
+    }
+
+    let mut circuits = DisjointSet::new(positions.len());
+    for &(i, j, _) in &distances[0..connection_count] {
+        circuits.union(i, j);
+    }
+
+    let mut sizes = circuits
+        .components()
+        .map(|circuit| circuit.len())
+        .collect::<Vec<_>>();
+    if sizes.len() < 3 {
+        bail!("Only {} circuits, expected at least 3", sizes.len());
+    }
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(sizes[0..3].iter().product())
+}
+
+/// Connects the closest junction boxes until they are all in one circuit, and multiplies the X
+/// co-ordinates of the last two to be connected.
+fn part2(positions: &[[i64; 3]]) -> Result<i64, Report> {
+    let mut circuits = DisjointSet::new(positions.len());
+    for (i, j, _) in find_distances(positions) {
+        if circuits.union(i, j) && circuits.component_count() == 1 {
+            return Ok(positions[i][0] * positions[j][0]);
+        }
+    }
+    bail!("Fewer than 2 junction boxes to connect");
+}
+
+/// Returns the distances between each pair of positions, sorted from shortest to longest distance.
+fn find_distances(positions: &[[i64; 3]]) -> Vec<(usize, usize, i64)> {
+    // Find the closest `connection_count` pairs of junction boxes.
+    let mut distances = Vec::new();
+    for (i, a) in positions.iter().enumerate() {
+        for (j, b) in positions[0..i].iter().enumerate() {
+            if i != j {
+                distances.push((i, j, distance_squared(a, b)));
+            }
+        }
+    }
+    distances.sort_by_key(|&(_, _, distance)| distance);
+    distances
+}
+
+fn distance_squared(a: &[i64; 3], b: &[i64; 3]) -> i64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(&a, &b)| (a - b) * (a - b))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::example_tests;
+
+    example_tests!(Day8, example);
+
+    #[test]
+    fn parse_example() {
+        assert_eq!(
+            parse(include_str!("../examples/example.txt").as_bytes()).unwrap(),
+            vec![
+                [162, 817, 812],
+                [57, 618, 57],
+                [906, 360, 560],
+                [592, 479, 940],
+                [352, 342, 300],
+                [466, 668, 158],
+                [542, 29, 236],
+                [431, 825, 988],
+                [739, 650, 466],
+                [52, 470, 668],
+                [216, 146, 977],
+                [819, 987, 18],
+                [117, 168, 530],
+                [805, 96, 715],
+                [346, 949, 466],
+                [970, 615, 88],
+                [941, 993, 340],
+                [862, 61, 35],
+                [984, 92, 344],
+                [425, 690, 689],
+            ]
+        );
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            connect_count_multiply(
+                &[
+                    [162, 817, 812],
+                    [57, 618, 57],
+                    [906, 360, 560],
+                    [592, 479, 940],
+                    [352, 342, 300],
+                    [466, 668, 158],
+                    [542, 29, 236],
+                    [431, 825, 988],
+                    [739, 650, 466],
+                    [52, 470, 668],
+                    [216, 146, 977],
+                    [819, 987, 18],
+                    [117, 168, 530],
+                    [805, 96, 715],
+                    [346, 949, 466],
+                    [970, 615, 88],
+                    [941, 993, 340],
+                    [862, 61, 35],
+                    [984, 92, 344],
+                    [425, 690, 689],
+                ],
+                10
+            )
+            .unwrap(),
+            40
+        );
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(
+            part2(&[
+                [162, 817, 812],
+                [57, 618, 57],
+                [906, 360, 560],
+                [592, 479, 940],
+                [352, 342, 300],
+                [466, 668, 158],
+                [542, 29, 236],
+                [431, 825, 988],
+                [739, 650, 466],
+                [52, 470, 668],
+                [216, 146, 977],
+                [819, 987, 18],
+                [117, 168, 530],
+                [805, 96, 715],
+                [346, 949, 466],
+                [970, 615, 88],
+                [941, 993, 340],
+                [862, 61, 35],
+                [984, 92, 344],
+                [425, 690, 689],
+            ])
+            .unwrap(),
+            25272
+        );
+    }
+
+    #[test]
+    fn too_few_junction_boxes() {
+        assert!(connect_count_multiply(&[[0, 0, 0], [1, 1, 1]], 1).is_err());
+        assert!(connect_count_multiply(&[[0, 0, 0], [1, 1, 1], [2, 2, 2]], 4).is_err());
+        assert!(part2(&[[0, 0, 0]]).is_err());
+    }
+}
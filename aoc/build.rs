@@ -0,0 +1,76 @@
+//! Finds the day crates in the workspace and generates the list of days for the runner.
+//!
+//! Each day crate lives in `YYYY/dayN`, and must export a `DayN` type implementing
+//! `utils::solution::Solution`. Days which aren't also dependencies in `aoc/Cargo.toml` are
+//! skipped with a warning.
+
+use std::{
+    env,
+    fmt::Write,
+    fs::{read_dir, read_to_string, write},
+    path::Path,
+};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest_dir = Path::new(&manifest_dir);
+    let workspace = manifest_dir.parent().unwrap();
+    let manifest_path = manifest_dir.join("Cargo.toml");
+    let manifest = read_to_string(&manifest_path).unwrap();
+    println!("cargo::rerun-if-changed={}", manifest_path.display());
+
+    let mut days = Vec::new();
+    for entry in read_dir(workspace).unwrap() {
+        let entry = entry.unwrap();
+        let Some(year) = entry
+            .file_name()
+            .to_str()
+            .filter(|name| name.len() == 4)
+            .and_then(|name| name.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        println!("cargo::rerun-if-changed={}", entry.path().display());
+        for day_entry in read_dir(entry.path()).unwrap() {
+            let day_entry = day_entry.unwrap();
+            let Some(day) = day_entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("day"))
+                .and_then(|day| day.parse::<u8>().ok())
+            else {
+                continue;
+            };
+            if day_entry.path().join("Cargo.toml").exists() {
+                days.push((year, day, day_entry.path()));
+            }
+        }
+    }
+    days.sort();
+
+    let mut source =
+        String::from("/// Every day in the workspace, in order.\npub static DAYS: &[Day] = &[\n");
+    for (year, day, dir) in days {
+        let package = format!("aoc-{year}-day{day}");
+        if !manifest.contains(&format!("\n{package} = ")) {
+            // The crate can't be used without the dependency, so skip it rather than breaking the
+            // build.
+            println!(
+                "cargo::warning=Skipping {year}/day{day} as it is missing from the runner's \
+                dependencies, add `{package} = {{ path = \"../{year}/day{day}\" }}` to \
+                aoc/Cargo.toml"
+            );
+            continue;
+        }
+        writeln!(
+            source,
+            "    Day {{ year: {year}, day: {day}, dir: {:?}, solve: solve::<aoc_{year}_day{day}::Day{day}>, parse: parse_only::<aoc_{year}_day{day}::Day{day}> }},",
+            dir.display().to_string()
+        )
+        .unwrap();
+    }
+    source.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    write(Path::new(&out_dir).join("days.rs"), source).unwrap();
+}
@@ -0,0 +1,188 @@
+//! Downloads puzzle inputs and examples from the puzzle website, caching them under the inputs
+//! directory so that each is only ever fetched once.
+
+use crate::Day;
+use eyre::{OptionExt, Report, WrapErr, bail};
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+/// The puzzle website, used unless `AOC_BASE_URL` or `--base-url` says otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+/// A client for the puzzle website, authenticated with a session cookie.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// Creates a client for the given base URL. The session is only needed once something is
+    /// actually requested.
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client with the session cookie from `AOC_SESSION`, and the base URL from
+    /// `AOC_BASE_URL` if `base_url` is `None`.
+    pub fn from_env(base_url: Option<&str>) -> Self {
+        let env_base_url = env::var("AOC_BASE_URL").ok();
+        let base_url = base_url
+            .or(env_base_url.as_deref())
+            .unwrap_or(DEFAULT_BASE_URL);
+        Self::new(base_url, env::var("AOC_SESSION").ok())
+    }
+
+    /// Fetches the given path from the website, returning the body of the response.
+    fn get(&self, path: &str) -> Result<String, Report> {
+        self.request("GET", path, None)
+    }
+
+    /// Posts the given form to a path on the website, returning the body of the response.
+    pub(crate) fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, Report> {
+        self.request("POST", path, Some(form))
+    }
+
+    fn request(
+        &self,
+        method: &str,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, Report> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_eyre("Set AOC_SESSION to your session cookie to use the puzzle website")?;
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        let response = match response {
+            Ok(response) => response,
+            Err(ureq::Error::Status(404, _)) => bail!("{} isn't available yet", url),
+            Err(ureq::Error::Status(status, response)) => bail!(
+                "Requesting {} failed with {} {}, is the session cookie still valid?",
+                url,
+                status,
+                response.status_text()
+            ),
+            Err(e) => return Err(e).wrap_err_with(|| format!("Requesting {}", url)),
+        };
+        Ok(response.into_string()?)
+    }
+
+    /// Returns the path of the given day's input, downloading it first if it isn't already
+    /// cached in the inputs directory.
+    pub fn input(&self, day: &Day, inputs: &Path) -> Result<PathBuf, Report> {
+        let path = day.input_path(inputs);
+        if !path.exists() {
+            let input = self.get(&format!("/{}/day/{}/input", day.year, day.day))?;
+            save(&path, &input)?;
+        }
+        Ok(path)
+    }
+
+    /// Returns the paths of the example blocks from the given day's puzzle description,
+    /// downloading the description first if it isn't already cached in the inputs directory.
+    ///
+    /// The examples are taken from every `<pre><code>` block, so may include things other than
+    /// inputs.
+    pub fn examples(&self, day: &Day, inputs: &Path) -> Result<Vec<PathBuf>, Report> {
+        let page_path = day.input_path(inputs).with_extension("html");
+        let page = if page_path.exists() {
+            read_to_string(&page_path)
+                .wrap_err_with(|| format!("Reading {}", page_path.display()))?
+        } else {
+            let page = self.get(&format!("/{}/day/{}", day.year, day.day))?;
+            save(&page_path, &page)?;
+            page
+        };
+        let mut paths = Vec::new();
+        for (index, block) in example_blocks(&page).into_iter().enumerate() {
+            let path = day.fetched_example_path(inputs, index + 1);
+            if !path.exists() {
+                save(&path, &block)?;
+            }
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+fn save(path: &Path, contents: &str) -> Result<(), Report> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(path, contents).wrap_err_with(|| format!("Writing {}", path.display()))
+}
+
+/// Returns the contents of each `<pre><code>` block in the given page, without any markup.
+fn example_blocks(page: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut rest = page;
+    while let Some(start) = rest.find("<pre><code>") {
+        rest = &rest[start + "<pre><code>".len()..];
+        let Some(end) = rest.find("</code></pre>") else {
+            break;
+        };
+        blocks.push(decode_html(&strip_tags(&rest[..end])));
+        rest = &rest[end..];
+    }
+    blocks
+}
+
+/// Returns the text of the given HTML without any tags.
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_example_blocks() {
+        let page = "\
+<p>For example:</p>
+<pre><code>3   4
+<em>4</em>   3
+</code></pre>
+<p>Then <code>x</code>:</p>
+<pre><code>a -&gt; b &amp;&amp; c&lt;d
+</code></pre>";
+        assert_eq!(example_blocks(page), ["3   4\n4   3\n", "a -> b && c<d\n"]);
+        assert!(example_blocks("<p>No examples</p>").is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+use eyre::{Report, bail};
+use std::{
+    fmt::{self, Display, Formatter},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use utils::{
+    args::Params,
+    solution::{Answers, Parts, parse_only, solve},
+};
+
+pub mod fetch;
+pub mod submit;
+pub mod watch;
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// A solution to one day's puzzle.
+#[derive(Clone, Copy, Debug)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// The directory of the day's crate.
+    pub dir: &'static str,
+    /// Parses the given input with the given parameters and solves the requested parts.
+    pub solve: fn(&str, &Params, Parts) -> Result<Answers, Report>,
+    /// Parses the given input with the given parameters, without solving anything.
+    pub parse: fn(&str, &Params) -> Result<(), Report>,
+}
+
+impl Day {
+    /// Returns the path of the input for this day within the given inputs directory, i.e.
+    /// `inputs/YYYY/DD.txt`.
+    pub fn input_path(&self, inputs: &Path) -> PathBuf {
+        inputs
+            .join(self.year.to_string())
+            .join(format!("{:02}.txt", self.day))
+    }
+
+    /// Returns the path of the given example block (counting from 1) downloaded from this day's
+    /// puzzle description within the given inputs directory, i.e. `inputs/YYYY/DD-exampleN.txt`.
+    pub fn fetched_example_path(&self, inputs: &Path, index: usize) -> PathBuf {
+        inputs
+            .join(self.year.to_string())
+            .join(format!("{:02}-example{}.txt", self.day, index))
+    }
+
+    /// Returns the path of the named example input for this day, i.e. `examples/NAME.txt` within
+    /// the day's crate. The expected answers are alongside it in `examples/NAME.toml`.
+    pub fn example_path(&self, name: &str) -> PathBuf {
+        Path::new(self.dir)
+            .join("examples")
+            .join(name)
+            .with_extension("txt")
+    }
+
+    /// Returns the path of the known answers for this day within the given answers directory,
+    /// i.e. `answers/YYYY/DD.toml`.
+    pub fn answers_path(&self, answers: &Path) -> PathBuf {
+        answers
+            .join(self.year.to_string())
+            .join(format!("{:02}.toml", self.day))
+    }
+
+    /// Returns the path of the record of answers submitted for this day within the given answers
+    /// directory, i.e. `answers/YYYY/DD.submissions.toml`.
+    pub fn submissions_path(&self, answers: &Path) -> PathBuf {
+        answers
+            .join(self.year.to_string())
+            .join(format!("{:02}.submissions.toml", self.day))
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}/{:02}", self.year, self.day)
+    }
+}
+
+/// A selection of days to run, either a whole year (`2024`) or a single day (`2024/16`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Selection {
+    pub year: u16,
+    pub day: Option<u8>,
+}
+
+impl Selection {
+    /// Returns whether the given day is included in this selection.
+    pub fn matches(&self, day: &Day) -> bool {
+        self.year == day.year && self.day.is_none_or(|d| d == day.day)
+    }
+}
+
+impl Display for Selection {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.day {
+            Some(day) => write!(f, "{}/{:02}", self.year, day),
+            None => write!(f, "{}", self.year),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = match s.split_once('/') {
+            Some((year, day)) => (year, Some(day)),
+            None => (s, None),
+        };
+        let year = year.parse()?;
+        let day = day.map(str::parse).transpose()?;
+        if day.is_some_and(|day| !(1..=25).contains(&day)) {
+            bail!("Invalid day in '{}'", s);
+        }
+        Ok(Self { year, day })
+    }
+}
+
+/// Returns the days matching any of the given selections, or every day if there are no
+/// selections.
+pub fn select(selections: &[Selection]) -> Result<Vec<Day>, Report> {
+    if selections.is_empty() {
+        return Ok(DAYS.to_vec());
+    }
+    for selection in selections {
+        if !DAYS.iter().any(|day| selection.matches(day)) {
+            bail!("No solutions found for {}", selection);
+        }
+    }
+    Ok(DAYS
+        .iter()
+        .filter(|day| selections.iter().any(|selection| selection.matches(day)))
+        .copied()
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_found() {
+        assert!(DAYS.iter().any(|day| day.year == 2022 && day.day == 1));
+        assert!(DAYS.iter().any(|day| day.year == 2024 && day.day == 16));
+        assert!(DAYS.is_sorted_by_key(|day| (day.year, day.day)));
+    }
+
+    #[test]
+    fn input_path() {
+        let day = select(&["2024/6".parse().unwrap()]).unwrap()[0];
+        assert_eq!(
+            day.input_path(Path::new("inputs")),
+            Path::new("inputs/2024/06.txt")
+        );
+    }
+
+    #[test]
+    fn example_path() {
+        let day = select(&["2024/6".parse().unwrap()]).unwrap()[0];
+        assert!(day.example_path("example").exists());
+    }
+
+    #[test]
+    fn parse_selection() {
+        assert_eq!(
+            "2024".parse::<Selection>().unwrap(),
+            Selection {
+                year: 2024,
+                day: None
+            }
+        );
+        assert_eq!(
+            "2025/11".parse::<Selection>().unwrap(),
+            Selection {
+                year: 2025,
+                day: Some(11)
+            }
+        );
+        assert!("2025/26".parse::<Selection>().is_err());
+        assert!("day1".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn select_days() {
+        let days = select(&["2022".parse().unwrap(), "2024/1".parse().unwrap()]).unwrap();
+        assert_eq!(
+            days.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["2022/01", "2022/02", "2024/01"]
+        );
+        assert!(select(&["2023".parse().unwrap()]).is_err());
+    }
+}
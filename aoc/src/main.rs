@@ -0,0 +1,616 @@
+use aoc::{
+    Day, Selection,
+    fetch::Client,
+    select,
+    submit::{Response, submit},
+    watch::{describe, modification_times, run_binary, run_target, targets, watched_paths},
+};
+use eyre::{OptionExt, Report, bail, eyre};
+use std::{
+    collections::HashMap,
+    env,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    thread::sleep,
+    time::Duration,
+};
+use utils::{
+    answers::KnownAnswers,
+    args::Params,
+    budget::{Budget, Timeout, parse_seconds, with_budget},
+    log::{Level, set_level},
+    solution::{Answer, Answers, Parts},
+};
+
+const USAGE: &str = "\
+Usage: aoc [--inputs <dir>] [--answers <dir>] [--record] [--example <name>] [--part 1|2]
+           [--param <key>=<value>]... [--fetch] [--base-url <url>] [-v | --trace]
+           [--timeout <seconds>] [--max-steps <n>] [YEAR[/DAY]...]
+       aoc submit [--inputs <dir>] [--answers <dir>] [--param <key>=<value>]... [--base-url <url>]
+                  YEAR/DAY 1|2 [ANSWER]
+       aoc watch [--inputs <dir>] [--interval <ms>] YEAR/DAY
+
+--fetch downloads any missing inputs and examples, using the session cookie in AOC_SESSION.
+--timeout and --max-steps limit how long each day may run, for days which report their progress.
+submit posts an answer, by default the one the day's binary prints for its input, and records
+the result in the answers directory.
+watch re-runs a day's examples and input whenever its source or inputs change.";
+
+fn main() -> Result<(), Report> {
+    let mut args = env::args().skip(1).peekable();
+    if args.next_if_eq("submit").is_some() {
+        return submit_answer(SubmitOptions::parse(args)?);
+    }
+    if args.next_if_eq("watch").is_some() {
+        return watch_day(WatchOptions::parse(args)?);
+    }
+    let options = Options::parse(args)?;
+    set_level(options.level);
+    let days = select(&options.selections)?;
+    let client = Client::from_env(options.base_url.as_deref());
+
+    let mut table = Table::default();
+    let mut failures = Vec::new();
+    let mut total_time = Duration::ZERO;
+    for day in days {
+        let path = match &options.example {
+            Some(name) => day.example_path(name),
+            None => day.input_path(&options.inputs),
+        };
+        if options.fetch
+            && options.example.is_none()
+            && let Err(e) = client
+                .input(&day, &options.inputs)
+                .and_then(|_| client.examples(&day, &options.inputs))
+        {
+            table.add_row([day.to_string(), "fetch failed".to_string()]);
+            failures.push((day, e));
+            continue;
+        }
+        if !path.exists() {
+            table.add_row([day.to_string(), String::new(), "no input".to_string()]);
+            continue;
+        }
+        match day_params(&day, &options)
+            .and_then(|params| run_day(&day, &path, &params, options.parts, options.budget))
+        {
+            Ok((answers, memory)) => {
+                total_time += answers.parse_time
+                    + [&answers.part1, &answers.part2]
+                        .into_iter()
+                        .flatten()
+                        .map(|answer| answer.time)
+                        .sum::<Duration>();
+                table.add_answers(&day, &answers, memory);
+                if let Err(e) = check_answers(&day, &answers, &options) {
+                    failures.push((day, e));
+                }
+            }
+            Err(e) => {
+                let status = if e.is::<Timeout>() {
+                    "timeout"
+                } else {
+                    "error"
+                };
+                table.add_row([day.to_string(), status.to_string()]);
+                failures.push((day, e));
+            }
+        }
+    }
+    table.print();
+    println!("\nTotal time: {}", format_time(total_time));
+
+    for (day, e) in &failures {
+        eprintln!("\n{}: {:?}", day, e);
+    }
+    if !failures.is_empty() {
+        bail!("{} days failed", failures.len());
+    }
+    Ok(())
+}
+
+/// Returns the parameters to solve the given day with: those given on the command line, on top of
+/// the example's own parameters when running an example.
+fn day_params(day: &Day, options: &Options) -> Result<Params, Report> {
+    let mut params = match &options.example {
+        Some(name) => KnownAnswers::load(&day.example_path(name).with_extension("toml"))?
+            .map(|known| known.params)
+            .unwrap_or_default(),
+        None => Params::default(),
+    };
+    params.extend(&options.params);
+    Ok(params)
+}
+
+/// Solves the given day, returning the answers along with the heap usage cells for the table.
+fn run_day(
+    day: &Day,
+    path: &Path,
+    params: &Params,
+    parts: Parts,
+    budget: Budget,
+) -> Result<(Answers, Vec<String>), Report> {
+    let input =
+        read_to_string(path).map_err(|e| eyre!("Reading input from {}: {}", path.display(), e))?;
+    let (answers, memory) =
+        measure_memory(|| with_budget(budget, || (day.solve)(&input, params, parts)));
+    Ok((answers?, memory))
+}
+
+/// The table headings for heap usage, if allocations are being counted.
+const MEMORY_HEADER: &[&str] = if cfg!(feature = "count-allocations") {
+    &["Peak heap", "Allocations"]
+} else {
+    &[]
+};
+
+#[cfg(feature = "count-allocations")]
+fn measure_memory<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let (result, stats) = utils::alloc::measure(f);
+    let cells = vec![
+        format_bytes(stats.peak_bytes),
+        stats.allocations.to_string(),
+    ];
+    (result, cells)
+}
+
+#[cfg(not(feature = "count-allocations"))]
+fn measure_memory<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    (f(), Vec::new())
+}
+
+/// Checks the answers for the given day against its known answers, first recording any new
+/// answers if requested.
+///
+/// When running an example its expected answers are used instead.
+fn check_answers(day: &Day, answers: &Answers, options: &Options) -> Result<(), Report> {
+    let path = match &options.example {
+        Some(name) => day.example_path(name).with_extension("toml"),
+        None => day.answers_path(&options.answers),
+    };
+    let mut known = KnownAnswers::load(&path)?.unwrap_or_default();
+    if options.record && known.record(answers) {
+        known.save(&path)?;
+    }
+    known.check(answers)
+}
+
+/// Submits an answer to the website, running the day's binary on its input to find it if it
+/// wasn't given.
+fn submit_answer(options: SubmitOptions) -> Result<(), Report> {
+    let &[day] = select(&[options.selection])?.as_slice() else {
+        bail!(USAGE);
+    };
+    let client = Client::from_env(options.base_url.as_deref());
+    let answer = match options.answer {
+        Some(answer) => answer,
+        None => {
+            let path = client.input(&day, &options.inputs)?;
+            let output = run_binary(&day, &path, &options.params, Some(options.part))?;
+            if options.part == 1 {
+                output.part1
+            } else {
+                output.part2
+            }
+            .ok_or_else(|| eyre!("{} part {} isn't solved yet", day, options.part))?
+        }
+    };
+    match submit(&client, &day, options.part, &answer, &options.answers)? {
+        Response::Verdict(verdict) => {
+            println!("{} part {}: {} is {}", day, options.part, answer, verdict);
+        }
+        Response::Wait(wait) => bail!(
+            "{} wasn't checked, wait {}s before submitting again",
+            answer,
+            wait.as_secs()
+        ),
+        Response::WrongLevel => bail!(
+            "{} part {} is already solved or isn't available yet",
+            day,
+            options.part
+        ),
+    }
+    Ok(())
+}
+
+/// Command-line options for submitting an answer.
+#[derive(Debug)]
+struct SubmitOptions {
+    inputs: PathBuf,
+    answers: PathBuf,
+    base_url: Option<String>,
+    /// The parameters to run the day's binary with, if the answer wasn't given.
+    params: Params,
+    selection: Selection,
+    part: u8,
+    /// The answer to submit, or `None` to run the day's binary on its input for it.
+    answer: Option<String>,
+}
+
+impl SubmitOptions {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Report> {
+        let mut inputs = PathBuf::from("inputs");
+        let mut answers = PathBuf::from("answers");
+        let mut base_url = None;
+        let mut params = Params::default();
+        let mut positional = Vec::new();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--inputs" => inputs = args.next().ok_or_eyre(USAGE)?.into(),
+                "--answers" => answers = args.next().ok_or_eyre(USAGE)?.into(),
+                "--param" => params.insert_arg(&args.next().ok_or_eyre(USAGE)?)?,
+                "--base-url" => base_url = Some(args.next().ok_or_eyre(USAGE)?),
+                _ if arg.starts_with('-') => bail!("Unknown option '{}'\n{}", arg, USAGE),
+                _ => positional.push(arg),
+            }
+        }
+        let [selection, part, answer @ ..] = positional.as_slice() else {
+            bail!(USAGE);
+        };
+        let selection = selection.parse::<Selection>()?;
+        if selection.day.is_none() || answer.len() > 1 {
+            bail!(USAGE);
+        }
+        let part = match part.as_str() {
+            "1" => 1,
+            "2" => 2,
+            _ => bail!(USAGE),
+        };
+        Ok(Self {
+            inputs,
+            answers,
+            base_url,
+            params,
+            selection,
+            part,
+            answer: answer.first().cloned(),
+        })
+    }
+}
+
+/// Runs the day on its examples and input whenever its source or inputs change, showing how the
+/// answers differ from the previous run. This never returns unless something goes wrong.
+fn watch_day(options: WatchOptions) -> Result<(), Report> {
+    let &[day] = select(&[options.selection])?.as_slice() else {
+        bail!(USAGE);
+    };
+    let paths = watched_paths(&day, &options.inputs);
+    let mut previous = HashMap::new();
+    let mut last_times = None;
+    loop {
+        let times = modification_times(&paths);
+        if last_times.as_ref() != Some(&times) {
+            println!(
+                "\n{} {}",
+                day,
+                if last_times.is_none() {
+                    "watching"
+                } else {
+                    "changed"
+                }
+            );
+            last_times = Some(times);
+            for target in targets(&day, &options.inputs)? {
+                match run_target(&day, &target) {
+                    Ok(output) => {
+                        println!("{}", describe(&target, &output, previous.get(&target.name)));
+                        previous.insert(target.name, output);
+                    }
+                    Err(e) => println!("{}: {}", target.name, e),
+                }
+            }
+        }
+        sleep(options.interval);
+    }
+}
+
+/// Command-line options for watch mode.
+#[derive(Debug)]
+struct WatchOptions {
+    inputs: PathBuf,
+    /// How often to check for changes.
+    interval: Duration,
+    selection: Selection,
+}
+
+impl WatchOptions {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Report> {
+        let mut inputs = PathBuf::from("inputs");
+        let mut interval = Duration::from_millis(500);
+        let mut selection = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--inputs" => inputs = args.next().ok_or_eyre(USAGE)?.into(),
+                "--interval" => {
+                    interval = Duration::from_millis(args.next().ok_or_eyre(USAGE)?.parse()?);
+                }
+                _ if arg.starts_with('-') || selection.is_some() => {
+                    bail!("Unexpected argument '{}'\n{}", arg, USAGE)
+                }
+                _ => selection = Some(arg.parse::<Selection>()?),
+            }
+        }
+        let selection = selection
+            .filter(|selection| selection.day.is_some())
+            .ok_or_eyre(USAGE)?;
+        Ok(Self {
+            inputs,
+            interval,
+            selection,
+        })
+    }
+}
+
+/// Command-line options for the runner.
+#[derive(Debug)]
+struct Options {
+    inputs: PathBuf,
+    answers: PathBuf,
+    record: bool,
+    example: Option<String>,
+    parts: Parts,
+    params: Params,
+    fetch: bool,
+    base_url: Option<String>,
+    level: Level,
+    /// The limits on how long each day may run.
+    budget: Budget,
+    selections: Vec<Selection>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Report> {
+        let mut options = Self {
+            inputs: PathBuf::from("inputs"),
+            answers: PathBuf::from("answers"),
+            record: false,
+            example: None,
+            parts: Parts::Both,
+            params: Params::default(),
+            fetch: false,
+            base_url: None,
+            level: Level::Quiet,
+            budget: Budget::default(),
+            selections: Vec::new(),
+        };
+        while let Some(arg) = args.next() {
+            if let Some(level) = Level::from_switch(&arg) {
+                options.level = level;
+                continue;
+            }
+            match arg.as_str() {
+                "--inputs" => {
+                    options.inputs = args.next().ok_or_eyre(USAGE)?.into();
+                }
+                "--answers" => {
+                    options.answers = args.next().ok_or_eyre(USAGE)?.into();
+                }
+                "--record" => options.record = true,
+                "--example" => {
+                    options.example = Some(args.next().ok_or_eyre(USAGE)?);
+                }
+                "--part" => options.parts = args.next().ok_or_eyre(USAGE)?.parse()?,
+                "--param" => options.params.insert_arg(&args.next().ok_or_eyre(USAGE)?)?,
+                "--fetch" => options.fetch = true,
+                "--base-url" => options.base_url = Some(args.next().ok_or_eyre(USAGE)?),
+                "--timeout" => {
+                    options.budget.time = Some(parse_seconds(&args.next().ok_or_eyre(USAGE)?)?);
+                }
+                "--max-steps" => {
+                    options.budget.steps = Some(args.next().ok_or_eyre(USAGE)?.parse()?);
+                }
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    std::process::exit(0);
+                }
+                _ if arg.starts_with('-') => bail!("Unknown option '{}'\n{}", arg, USAGE),
+                _ => options.selections.push(arg.parse()?),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// A table of answers and timings, with columns aligned when printed.
+struct Table {
+    rows: Vec<Vec<String>>,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        let header = ["Day", "Parse", "Part 1", "Time", "Part 2", "Time"];
+        Self {
+            rows: vec![
+                header
+                    .iter()
+                    .chain(MEMORY_HEADER)
+                    .map(|heading| heading.to_string())
+                    .collect(),
+            ],
+        }
+    }
+}
+
+impl Table {
+    /// Adds a row, with `-` in any empty or missing cells so that every row has one cell for each
+    /// column.
+    fn add_row(&mut self, row: impl IntoIterator<Item = String>) {
+        let mut row = row
+            .into_iter()
+            .map(|cell| {
+                if cell.is_empty() {
+                    "-".to_string()
+                } else {
+                    cell
+                }
+            })
+            .collect::<Vec<_>>();
+        row.resize(row.len().max(self.rows[0].len()), "-".to_string());
+        self.rows.push(row);
+    }
+
+    fn add_answers(&mut self, day: &Day, answers: &Answers, memory: Vec<String>) {
+        let (part1, time1) = format_answer(&answers.part1);
+        let (part2, time2) = format_answer(&answers.part2);
+        self.add_row(
+            [
+                day.to_string(),
+                format_time(answers.parse_time),
+                part1,
+                time1,
+                part2,
+                time2,
+            ]
+            .into_iter()
+            .chain(memory),
+        );
+    }
+
+    fn print(&self) {
+        let mut widths = Vec::new();
+        for row in &self.rows {
+            widths.resize(widths.len().max(row.len()), 0);
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        for row in &self.rows {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:width$}", cell))
+                .collect::<Vec<_>>()
+                .join("  ");
+            println!("{}", line.trim_end());
+        }
+    }
+}
+
+fn format_answer(answer: &Option<Answer>) -> (String, String) {
+    match answer {
+        None => (String::new(), String::new()),
+        Some(Answer { value: None, .. }) => ("not solved".to_string(), String::new()),
+        Some(Answer {
+            value: Some(value),
+            time,
+        }) => (value.clone(), format_time(*time)),
+    }
+}
+
+fn format_time(time: Duration) -> String {
+    format!("{:.1?}", time)
+}
+
+#[cfg(feature = "count-allocations")]
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn parse_options() {
+        let options = Options::parse(args(&[
+            "--inputs", "in", "--part", "2", "--param", "width=11", "2024/6", "2025",
+        ]))
+        .unwrap();
+        assert_eq!(options.inputs, Path::new("in"));
+        assert_eq!(options.parts, Parts::Part2);
+        assert_eq!(options.params.get("width", 0).unwrap(), 11);
+        assert_eq!(options.level, Level::Quiet);
+        assert_eq!(options.budget, Budget::default());
+        assert_eq!(options.selections.len(), 2);
+    }
+
+    #[test]
+    fn parse_budget() {
+        let options = Options::parse(args(&["--timeout", "10", "--max-steps", "5000"])).unwrap();
+        assert_eq!(
+            options.budget,
+            Budget {
+                time: Some(Duration::from_secs(10)),
+                steps: Some(5000),
+            }
+        );
+        assert!(Options::parse(args(&["--timeout"])).is_err());
+    }
+
+    #[test]
+    fn parse_verbosity() {
+        assert_eq!(Options::parse(args(&["-v"])).unwrap().level, Level::Verbose);
+        assert_eq!(
+            Options::parse(args(&["--trace", "2024"])).unwrap().level,
+            Level::Trace
+        );
+    }
+
+    #[test]
+    fn parse_submit_options() {
+        let options = SubmitOptions::parse(args(&["2025/11", "1", "42"])).unwrap();
+        assert_eq!(options.selection.day, Some(11));
+        assert_eq!(options.part, 1);
+        assert_eq!(options.answer.as_deref(), Some("42"));
+        let options = SubmitOptions::parse(args(&[
+            "--answers",
+            "a",
+            "--param",
+            "width=11",
+            "2025/11",
+            "2",
+        ]))
+        .unwrap();
+        assert_eq!(options.answers, Path::new("a"));
+        assert_eq!(options.params.get("width", 0).unwrap(), 11);
+        assert_eq!(options.answer, None);
+        assert!(SubmitOptions::parse(args(&["2025", "1"])).is_err());
+        assert!(SubmitOptions::parse(args(&["2025/11", "3"])).is_err());
+        assert!(SubmitOptions::parse(args(&["2025/11", "1", "42", "43"])).is_err());
+    }
+
+    #[test]
+    fn parse_watch_options() {
+        let options = WatchOptions::parse(args(&["--interval", "100", "2025/10"])).unwrap();
+        assert_eq!(options.interval, Duration::from_millis(100));
+        assert_eq!(options.selection.day, Some(10));
+        assert!(WatchOptions::parse(args(&["2025"])).is_err());
+        assert!(WatchOptions::parse(args(&["2025/10", "2025/11"])).is_err());
+    }
+
+    #[test]
+    fn table_rows_filled() {
+        let mut table = Table::default();
+        table.add_row(["2025/01".to_string(), "fetch failed".to_string()]);
+        table.add_row(["2025/02".to_string(), String::new(), "no input".to_string()]);
+        let columns = table.rows[0].len();
+        assert!(table.rows.iter().all(|row| row.len() == columns));
+        assert_eq!(table.rows[2][..4], ["2025/02", "-", "no input", "-"]);
+    }
+
+    #[test]
+    fn parse_invalid_options() {
+        assert!(Options::parse(args(&["--part", "3"])).is_err());
+        assert!(Options::parse(args(&["--inputs"])).is_err());
+        assert!(Options::parse(args(&["--param", "width"])).is_err());
+        assert!(Options::parse(args(&["--quiet"])).is_err());
+    }
+}
@@ -0,0 +1,382 @@
+//! Submits answers to the puzzle website, keeping a local record of every submission so that an
+//! answer which is known to be wrong is never submitted twice.
+
+use crate::{
+    Day,
+    fetch::{Client, strip_tags},
+};
+use eyre::{Report, WrapErr, bail, eyre};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::{create_dir_all, read_to_string, write},
+    io::ErrorKind,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use utils::answers::KnownAnswers;
+
+/// What the website said about a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Response {
+    /// The answer was checked.
+    Verdict(Verdict),
+    /// An answer was submitted too recently, so this one wasn't checked.
+    Wait(Duration),
+    /// The part has already been solved, or is part 2 before part 1 has been.
+    WrongLevel,
+}
+
+impl Response {
+    /// Parses the page returned after submitting an answer.
+    pub fn parse(page: &str) -> Result<Self, Report> {
+        let text = page_text(page);
+        Ok(if text.contains("That's the right answer") {
+            Self::Verdict(Verdict::Correct)
+        } else if text.contains("That's not the right answer") {
+            Self::Verdict(if text.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if text.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if text.contains("You gave an answer too recently") {
+            Self::Wait(cooldown(&text).ok_or_else(|| eyre!("No wait time in '{}'", text))?)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            bail!("Unexpected response to answer: '{}'", text);
+        })
+    }
+}
+
+/// The result of checking an answer.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Wrong => "wrong",
+        })
+    }
+}
+
+/// The answers submitted for one day, as stored in `answers/YYYY/DD.submissions.toml`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Submissions {
+    /// The time before which no more answers may be submitted, in seconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part1: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part2: Vec<Submission>,
+}
+
+/// An answer which was submitted, and what the website said about it.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Submissions {
+    /// Loads the submissions from the given file, or returns an empty record if it doesn't
+    /// exist.
+    pub fn load(path: &Path) -> Result<Self, Report> {
+        match read_to_string(path) {
+            Ok(contents) => {
+                toml::from_str(&contents).wrap_err_with(|| format!("Parsing {}", path.display()))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(eyre!("Reading {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Writes the submissions to the given file, creating its directory if necessary.
+    pub fn save(&self, path: &Path) -> Result<(), Report> {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        write(path, toml::to_string(self)?).wrap_err_with(|| format!("Writing {}", path.display()))
+    }
+
+    fn part(&self, part: u8) -> &Vec<Submission> {
+        if part == 1 { &self.part1 } else { &self.part2 }
+    }
+
+    /// Returns an error if the given answer shouldn't be submitted at the given time, because
+    /// another answer was submitted too recently, the part is already solved, or the answer is
+    /// known to be wrong.
+    ///
+    /// Numeric answers are also checked against the bounds given by earlier answers which were too
+    /// high or too low.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), Report> {
+        if let Some(wait_until) = self.wait_until
+            && wait_until > now
+        {
+            bail!("Wait another {}s before submitting", wait_until - now);
+        }
+        let submissions = self.part(part);
+        if let Some(correct) = submissions
+            .iter()
+            .find(|submission| submission.verdict == Verdict::Correct)
+        {
+            bail!("Part {} was already solved with {}", part, correct.answer);
+        }
+        if let Some(previous) = submissions
+            .iter()
+            .find(|submission| submission.answer == answer)
+        {
+            bail!(
+                "{} was already submitted and was {}",
+                answer,
+                previous.verdict
+            );
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            for submission in submissions {
+                let Ok(previous) = submission.answer.parse::<i128>() else {
+                    continue;
+                };
+                if (submission.verdict == Verdict::TooHigh && value >= previous)
+                    || (submission.verdict == Verdict::TooLow && value <= previous)
+                {
+                    bail!(
+                        "{} must be {} too, as {} was",
+                        answer,
+                        submission.verdict,
+                        previous
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Records the verdict on an answer to the given part.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        let submissions = if part == 1 {
+            &mut self.part1
+        } else {
+            &mut self.part2
+        };
+        submissions.push(Submission {
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+}
+
+/// Submits the answer to the given part of a day and records the result in the answers
+/// directory, unless the answer is known to be wrong or another was submitted too recently.
+///
+/// A correct answer is also saved as the day's known answer for that part.
+pub fn submit(
+    client: &Client,
+    day: &Day,
+    part: u8,
+    answer: &str,
+    answers: &Path,
+) -> Result<Response, Report> {
+    if part != 1 && part != 2 {
+        bail!("Invalid part {}", part);
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let path = day.submissions_path(answers);
+    let mut submissions = Submissions::load(&path)?;
+    submissions.check(part, answer, now)?;
+
+    let page = client.post(
+        &format!("/{}/day/{}/answer", day.year, day.day),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let response = Response::parse(&page)?;
+    if let Some(wait) = cooldown(&page_text(&page)) {
+        submissions.wait_until = Some(now + wait.as_secs());
+    }
+    if let Response::Verdict(verdict) = response {
+        submissions.record(part, answer, verdict);
+        if verdict == Verdict::Correct {
+            let known_path = day.answers_path(answers);
+            let mut known = KnownAnswers::load(&known_path)?.unwrap_or_default();
+            let known_answer = if part == 1 {
+                &mut known.part1
+            } else {
+                &mut known.part2
+            };
+            *known_answer = Some(answer.to_string());
+            known.save(&known_path)?;
+        }
+    }
+    submissions.save(&path)?;
+    Ok(response)
+}
+
+/// Returns the text of a page with all whitespace collapsed to single spaces.
+fn page_text(page: &str) -> String {
+    strip_tags(page)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns how long the given response text says to wait before submitting another answer, if
+/// it says.
+fn cooldown(text: &str) -> Option<Duration> {
+    if let Some(start) = text.find("You have ") {
+        let rest = &text[start + "You have ".len()..];
+        let end = rest.find(" left to wait")?;
+        let mut seconds = 0;
+        for amount in rest[..end].split_whitespace() {
+            let unit = amount.chars().last()?;
+            let number = amount[..amount.len() - unit.len_utf8()]
+                .parse::<u64>()
+                .ok()?;
+            seconds += match unit {
+                'h' => number * 3600,
+                'm' => number * 60,
+                's' => number,
+                _ => return None,
+            };
+        }
+        Some(Duration::from_secs(seconds))
+    } else if text.contains("wait one minute before trying again") {
+        Some(Duration::from_secs(60))
+    } else {
+        let start = text.find("wait ")? + "wait ".len();
+        let (minutes, rest) = text[start..].split_once(' ')?;
+        if !rest.starts_with("minutes before trying again") {
+            return None;
+        }
+        Some(Duration::from_secs(minutes.parse::<u64>().ok()? * 60))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(text: &str) -> String {
+        format!(
+            "<html><main>\n<article><p>{}</p></article>\n</main></html>",
+            text
+        )
+    }
+
+    #[test]
+    fn parse_responses() {
+        assert_eq!(
+            Response::parse(&article(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+            ))
+            .unwrap(),
+            Response::Verdict(Verdict::Correct)
+        );
+        assert_eq!(
+            Response::parse(&article(
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again."
+            ))
+            .unwrap(),
+            Response::Verdict(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Response::parse(&article(
+                "That's not the right answer; your answer is too low."
+            ))
+            .unwrap(),
+            Response::Verdict(Verdict::TooLow)
+        );
+        assert_eq!(
+            Response::parse(&article("That's not the right answer.")).unwrap(),
+            Response::Verdict(Verdict::Wrong)
+        );
+        assert_eq!(
+            Response::parse(&article(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."
+            ))
+            .unwrap(),
+            Response::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(
+            Response::parse(&article(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+            .unwrap(),
+            Response::WrongLevel
+        );
+        assert!(Response::parse(&article("Something else")).is_err());
+    }
+
+    #[test]
+    fn cooldowns() {
+        assert_eq!(
+            cooldown("You have 34s left to wait."),
+            Some(Duration::from_secs(34))
+        );
+        assert_eq!(
+            cooldown("Please wait one minute before trying again."),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            cooldown("Please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(cooldown("That's the right answer!"), None);
+        assert_eq!(
+            cooldown("You have 1h 2m 3s left to wait."),
+            Some(Duration::from_secs(3723))
+        );
+        assert_eq!(cooldown("You have 5分 left to wait."), None);
+        assert_eq!(cooldown("You have 1h 2x left to wait."), None);
+    }
+
+    #[test]
+    fn check_submissions() {
+        let mut submissions = Submissions::default();
+        assert!(submissions.check(1, "100", 0).is_ok());
+        submissions.record(1, "100", Verdict::TooHigh);
+        submissions.record(1, "10", Verdict::TooLow);
+        submissions.record(1, "abc", Verdict::Wrong);
+        assert!(submissions.check(1, "50", 0).is_ok());
+        assert!(submissions.check(1, "100", 0).is_err());
+        assert!(submissions.check(1, "150", 0).is_err());
+        assert!(submissions.check(1, "5", 0).is_err());
+        assert!(submissions.check(1, "abc", 0).is_err());
+        assert!(submissions.check(2, "100", 0).is_ok());
+
+        submissions.record(1, "50", Verdict::Correct);
+        assert!(submissions.check(1, "51", 0).is_err());
+
+        submissions.wait_until = Some(60);
+        assert!(submissions.check(2, "1", 30).is_err());
+        assert!(submissions.check(2, "1", 60).is_ok());
+    }
+
+    #[test]
+    fn submissions_round_trip() {
+        let mut submissions = Submissions {
+            wait_until: Some(1234),
+            ..Submissions::default()
+        };
+        submissions.record(2, "42", Verdict::TooLow);
+        let contents = toml::to_string(&submissions).unwrap();
+        assert!(contents.contains("verdict = \"too-low\""));
+        assert_eq!(
+            toml::from_str::<Submissions>(&contents).unwrap(),
+            submissions
+        );
+    }
+}
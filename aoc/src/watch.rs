@@ -0,0 +1,264 @@
+//! Re-runs a day's binary on its examples and input whenever its source or inputs change.
+
+use crate::Day;
+use eyre::{Report, WrapErr, bail, eyre};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fs::{metadata, read_dir},
+    path::{Path, PathBuf, absolute},
+    process::Command,
+    time::SystemTime,
+};
+use utils::{answers::KnownAnswers, args::Params};
+
+/// The answers from a day binary's `--json` output.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct Output {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// An input to run a day on, either one of its examples or the real input.
+#[derive(Clone, Debug)]
+pub struct Target {
+    pub name: String,
+    pub input: PathBuf,
+    /// The expected answers and parameters, for an example.
+    pub expected: KnownAnswers,
+}
+
+/// Returns the files and directories to watch for the given day: its manifest, source, examples
+/// and input.
+pub fn watched_paths(day: &Day, inputs: &Path) -> Vec<PathBuf> {
+    let dir = Path::new(day.dir);
+    vec![
+        dir.join("Cargo.toml"),
+        dir.join("src"),
+        dir.join("examples"),
+        day.input_path(inputs),
+    ]
+}
+
+/// Returns the modification time of every file within the given paths, so that any change can be
+/// spotted by comparing them. Missing paths are skipped.
+pub fn modification_times(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            times.insert(path, modified);
+        }
+    }
+    times
+}
+
+/// Returns the day's examples in order of name, followed by its real input if there is one.
+pub fn targets(day: &Day, inputs: &Path) -> Result<Vec<Target>, Report> {
+    let mut targets = Vec::new();
+    if let Ok(entries) = read_dir(Path::new(day.dir).join("examples")) {
+        for entry in entries {
+            let input = entry?.path();
+            if input.extension().is_none_or(|extension| extension != "txt") {
+                continue;
+            }
+            targets.push(Target {
+                name: input.file_stem().unwrap().to_string_lossy().into_owned(),
+                expected: KnownAnswers::load(&input.with_extension("toml"))?.unwrap_or_default(),
+                input,
+            });
+        }
+    }
+    targets.sort_by(|a, b| a.name.cmp(&b.name));
+    let input = day.input_path(inputs);
+    if input.exists() {
+        targets.push(Target {
+            name: "input".to_string(),
+            input,
+            expected: KnownAnswers::default(),
+        });
+    }
+    Ok(targets)
+}
+
+/// Builds the day's binary if necessary and runs it on the given target, with the target's
+/// parameters.
+pub fn run_target(day: &Day, target: &Target) -> Result<Output, Report> {
+    run_binary(day, &target.input, &target.expected.params, None)
+}
+
+/// Builds the day's binary if necessary and runs it on the given input with the given
+/// parameters, solving only the given part (1 or 2) or both if it is `None`.
+pub fn run_binary(
+    day: &Day,
+    input: &Path,
+    params: &Params,
+    part: Option<u8>,
+) -> Result<Output, Report> {
+    // The binary runs in the day's directory, so a relative input path would be looked up there.
+    let input = absolute(input)?;
+    let mut command = Command::new(env!("CARGO"));
+    command
+        .current_dir(day.dir)
+        .args(["run", "--release", "--quiet", "--package"])
+        .arg(format!("aoc-{}-day{}", day.year, day.day))
+        .args(["--", "--json", "--input"])
+        .arg(&input);
+    if let Some(part) = part {
+        command.arg("--part").arg(part.to_string());
+    }
+    for (key, value) in params.iter() {
+        command.arg("--param").arg(format!("{}={}", key, value));
+    }
+    let output = command
+        .output()
+        .wrap_err_with(|| format!("Running {}", day))?;
+    if !output.status.success() {
+        bail!("{}", String::from_utf8_lossy(&output.stderr).trim_end());
+    }
+    parse_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parses the `--json` output of a day binary, ignoring anything else it printed.
+fn parse_output(stdout: &str) -> Result<Output, Report> {
+    let line = stdout
+        .lines()
+        .rfind(|line| line.starts_with('{'))
+        .ok_or_else(|| eyre!("No answers in output '{}'", stdout))?;
+    Ok(serde_json::from_str(line)?)
+}
+
+/// Describes the answers for a target, along with how they differ from the previous run and from
+/// the expected answers.
+pub fn describe(target: &Target, output: &Output, previous: Option<&Output>) -> String {
+    let parts = [
+        (
+            "part 1",
+            &output.part1,
+            previous.map(|p| &p.part1),
+            &target.expected.part1,
+        ),
+        (
+            "part 2",
+            &output.part2,
+            previous.map(|p| &p.part2),
+            &target.expected.part2,
+        ),
+    ]
+    .into_iter()
+    .map(|(part, answer, previous, expected)| {
+        let mut description = format!("{} {}", part, answer.as_deref().unwrap_or("not solved"));
+        if let Some(previous) = previous
+            && previous != answer
+        {
+            description += &format!(" (was {})", previous.as_deref().unwrap_or("not solved"));
+        }
+        if let Some(expected) = expected
+            && Some(expected) != answer.as_ref()
+        {
+            description += &format!(" (expected {})", expected);
+        }
+        description
+    })
+    .collect::<Vec<_>>();
+    format!("{}: {}", target.name, parts.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::select;
+
+    fn target(expected_part2: Option<&str>) -> Target {
+        Target {
+            name: "example".to_string(),
+            input: PathBuf::from("example.txt"),
+            expected: KnownAnswers {
+                part2: expected_part2.map(String::from),
+                ..KnownAnswers::default()
+            },
+        }
+    }
+
+    #[test]
+    fn parse_json_output() {
+        let output = parse_output(
+            "debugging\n{\"year\":2025,\"day\":8,\"part1\":\"40\",\"part2\":null,\"timings\":{}}\n",
+        )
+        .unwrap();
+        assert_eq!(
+            output,
+            Output {
+                part1: Some("40".to_string()),
+                part2: None,
+            }
+        );
+        assert!(parse_output("Part 1: 40\n").is_err());
+    }
+
+    #[test]
+    fn describe_changes() {
+        let output = Output {
+            part1: Some("40".to_string()),
+            part2: Some("7".to_string()),
+        };
+        assert_eq!(
+            describe(&target(None), &output, None),
+            "example: part 1 40, part 2 7"
+        );
+        let previous = Output {
+            part1: Some("40".to_string()),
+            part2: None,
+        };
+        assert_eq!(
+            describe(&target(Some("8")), &output, Some(&previous)),
+            "example: part 1 40, part 2 7 (was not solved) (expected 8)"
+        );
+    }
+
+    #[test]
+    fn find_targets() {
+        let day = select(&["2025/8".parse().unwrap()]).unwrap()[0];
+        let targets = targets(&day, Path::new("no-inputs")).unwrap();
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].name, "example");
+        assert_eq!(
+            targets[0].expected.params.get("connections", 0).unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn spot_changes() {
+        let day = select(&["2025/8".parse().unwrap()]).unwrap()[0];
+        let times = modification_times(&watched_paths(&day, Path::new("no-inputs")));
+        assert!(times.keys().any(|path| path.ends_with("src/lib.rs")));
+        assert!(
+            times
+                .keys()
+                .any(|path| path.ends_with("examples/example.toml"))
+        );
+    }
+
+    #[test]
+    fn run_binary_with_params() {
+        let day = select(&["2025/8".parse().unwrap()]).unwrap()[0];
+        let mut params = Params::default();
+        params.insert("connections", "10");
+        let output = run_binary(&day, &day.example_path("example"), &params, Some(1)).unwrap();
+        assert_eq!(
+            output,
+            Output {
+                part1: Some("40".to_string()),
+                part2: None,
+            }
+        );
+    }
+}
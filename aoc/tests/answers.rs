@@ -0,0 +1,82 @@
+//! Runs every day with a local input against its known answers in `answers/YYYY/DD.toml`.
+//!
+//! Inputs are read from `inputs/YYYY/DD.txt` in the workspace, or from the directory given by the
+//! `AOC_INPUTS` environment variable. Days without an input or known answers are skipped, but the
+//! test fails if that leaves nothing to check.
+//!
+//! Puzzle inputs aren't committed, so this is ignored by default. Some days are slow without
+//! optimisations, so it is best run with
+//! `cargo test --release -p aoc --test answers -- --ignored`.
+//!
+//! The synthetic inputs in `tests/known` do have their answers committed, and are always checked
+//! against the day binaries.
+
+use aoc::{DAYS, watch::run_binary};
+use std::{
+    env,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+use utils::{answers::KnownAnswers, solution::Parts};
+
+#[test]
+#[ignore = "needs puzzle inputs in inputs/ or AOC_INPUTS"]
+fn known_answers() {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let inputs = env::var_os("AOC_INPUTS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace.join("inputs"));
+    let answers = workspace.join("answers");
+
+    let mut failures = Vec::new();
+    let mut without_input = 0;
+    let mut without_answers = 0;
+    let mut checked = 0;
+    for day in DAYS {
+        let Ok(input) = read_to_string(day.input_path(&inputs)) else {
+            without_input += 1;
+            continue;
+        };
+        let Some(known) = KnownAnswers::load(&day.answers_path(&answers)).unwrap() else {
+            without_answers += 1;
+            continue;
+        };
+        checked += 1;
+        if let Err(e) = (day.solve)(&input, &known.params, Parts::Both)
+            .and_then(|answers| known.check(&answers))
+        {
+            failures.push(format!("{}: {}", day, e));
+        }
+    }
+    println!(
+        "Checked {} days, skipped {} without an input and {} without known answers",
+        checked, without_input, without_answers
+    );
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    assert!(
+        checked > 0,
+        "No days were checked: {} have no input in {}, and {} have no known answers in {}",
+        without_input,
+        inputs.display(),
+        without_answers,
+        answers.display()
+    );
+}
+
+#[test]
+fn committed_answers() {
+    let known = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/known");
+    let mut checked = 0;
+    for day in DAYS {
+        let Some(expected) = KnownAnswers::load(&day.answers_path(&known.join("answers"))).unwrap()
+        else {
+            continue;
+        };
+        let input = day.input_path(&known.join("inputs"));
+        let output = run_binary(day, &input, &expected.params, None).unwrap();
+        assert_eq!(output.part1, expected.part1, "{} part 1", day);
+        assert_eq!(output.part2, expected.part2, "{} part 2", day);
+        checked += 1;
+    }
+    assert!(checked > 0, "No answers in {}", known.display());
+}
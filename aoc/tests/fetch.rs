@@ -0,0 +1,60 @@
+//! Tests downloading inputs against a mock of the puzzle website.
+
+mod common;
+
+use aoc::{fetch::Client, select};
+use common::{MockServer, Request, TempDir};
+use std::fs::read_to_string;
+
+fn puzzle_site(request: &Request) -> (u16, String) {
+    match request.path.as_str() {
+        "/2024/day/1/input" => (200, "3   4\n4   3\n".to_string()),
+        "/2024/day/1" => (
+            200,
+            "<p>For example:</p>\n<pre><code>3   4\n<em>4</em>   3\n</code></pre>".to_string(),
+        ),
+        _ => (404, "Not found".to_string()),
+    }
+}
+
+#[test]
+fn fetch_and_cache() {
+    let server = MockServer::start(puzzle_site);
+    let inputs = TempDir::new("fetch");
+    let client = Client::new(&server.url, Some("abc123".to_string()));
+    let day = select(&["2024/1".parse().unwrap()]).unwrap()[0];
+
+    let path = client.input(&day, &inputs.0).unwrap();
+    assert_eq!(path, inputs.0.join("2024/01.txt"));
+    assert_eq!(read_to_string(&path).unwrap(), "3   4\n4   3\n");
+    let examples = client.examples(&day, &inputs.0).unwrap();
+    assert_eq!(examples, [inputs.0.join("2024/01-example1.txt")]);
+    assert_eq!(read_to_string(&examples[0]).unwrap(), "3   4\n4   3\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].headers["cookie"], "session=abc123");
+    assert!(requests[0].headers["user-agent"].starts_with("aoc-runner/"));
+
+    // Everything is cached now, so nothing more should be requested.
+    client.input(&day, &inputs.0).unwrap();
+    client.examples(&day, &inputs.0).unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn fetch_errors() {
+    let server = MockServer::start(puzzle_site);
+    let inputs = TempDir::new("fetch-errors");
+    let day = select(&["2024/2".parse().unwrap()]).unwrap()[0];
+
+    let client = Client::new(&server.url, None);
+    assert!(client.input(&day, &inputs.0).is_err());
+    assert!(server.requests().is_empty());
+
+    let client = Client::new(&server.url, Some("abc123".to_string()));
+    let error = client.input(&day, &inputs.0).unwrap_err().to_string();
+    assert!(error.contains("isn't available yet"), "{}", error);
+    assert!(!day.input_path(&inputs.0).exists());
+}
@@ -0,0 +1,108 @@
+//! Checks that every day's parser returns an error rather than panicking on malformed input.
+//!
+//! The inputs are awkward fixed strings, every prefix of each example, and seeded random mutations
+//! of the examples. The fuzz targets in `fuzz/` explore much further, this just catches
+//! regressions on stable.
+
+use aoc::DAYS;
+use std::{
+    fs::{read_dir, read_to_string},
+    panic::{self, catch_unwind},
+    path::Path,
+};
+use utils::{args::Params, random::Rng};
+
+const AWKWARD_INPUTS: &[&str] = &[
+    "",
+    "\n",
+    "\n\n\n",
+    " ",
+    "x",
+    "0",
+    "-",
+    "-1",
+    "99999999999999999999999999",
+    "1-",
+    "1,",
+    ":",
+    "a:",
+    "[",
+    "] (",
+    "[.] (",
+    "[.] () {}",
+    "[#] (9) {1}",
+    "[#] (99) {1}",
+    "p=1,2 v=",
+    "p=1,2 v=3,4",
+    "#",
+    "#\n##",
+    "é",
+    "\r\n",
+];
+
+/// Characters to mutate the examples with, including a multi-byte one to catch slicing in the
+/// middle of a character.
+const MUTATIONS: &[char] = &[
+    '0', '1', '9', '-', '+', ',', '.', ':', '#', '@', '[', ']', '(', ')', '{', '}', '<', '>', '=',
+    '|', ' ', '\n', '^', 'v', 'S', 'E', 'a', 'é',
+];
+
+const MUTANTS_PER_EXAMPLE: usize = 200;
+
+fn example_inputs(dir: &Path) -> Vec<String> {
+    let Ok(entries) = read_dir(dir.join("examples")) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .map(|path| read_to_string(path).unwrap())
+        .collect()
+}
+
+fn mutate(rng: &mut Rng, example: &str) -> String {
+    let mut chars = example.chars().collect::<Vec<_>>();
+    for _ in 0..rng.range(1..4) {
+        let index = rng.index(chars.len() + 1);
+        match rng.range(0..3) {
+            0 if index < chars.len() => {
+                chars.remove(index);
+            }
+            1 if index < chars.len() => chars[index] = *rng.choose(MUTATIONS),
+            _ => chars.insert(index, *rng.choose(MUTATIONS)),
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[test]
+fn parsers_are_total() {
+    panic::set_hook(Box::new(|_| {}));
+    let mut failures = Vec::new();
+    for day in DAYS {
+        let mut inputs = AWKWARD_INPUTS
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let mut rng = Rng::new(u64::from(day.year) * 100 + u64::from(day.day));
+        for example in example_inputs(Path::new(day.dir)) {
+            inputs.extend(
+                example
+                    .char_indices()
+                    .map(|(i, _)| example[..i].to_string()),
+            );
+            inputs.extend((0..MUTANTS_PER_EXAMPLE).map(|_| mutate(&mut rng, &example)));
+        }
+        if let Some(input) = inputs.into_iter().find(|input| {
+            catch_unwind(|| {
+                let _ = (day.parse)(input, &Params::default());
+            })
+            .is_err()
+        }) {
+            failures.push(format!("{}: panicked parsing {:?}", day, input));
+        }
+    }
+    let _ = panic::take_hook();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
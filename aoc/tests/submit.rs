@@ -0,0 +1,91 @@
+//! Tests submitting answers against a stub of the puzzle website.
+
+mod common;
+
+use aoc::{
+    fetch::Client,
+    select,
+    submit::{Response, Submissions, Verdict, submit},
+};
+use common::{MockServer, Request, TempDir};
+use std::time::Duration;
+use utils::answers::KnownAnswers;
+
+fn answer_page(text: &str) -> String {
+    format!(
+        "<html><main>\n<article><p>{}</p></article>\n</main></html>",
+        text
+    )
+}
+
+fn puzzle_site(request: &Request) -> (u16, String) {
+    if request.method != "POST" || request.path != "/2025/day/11/answer" {
+        return (404, "Not found".to_string());
+    }
+    let text = match request.body.as_str() {
+        "level=1&answer=100" => {
+            "That's not the right answer; your answer is too high.  Please wait one minute before \
+             trying again."
+        }
+        "level=1&answer=50" => "That's the right answer!  You are one gold star closer.",
+        "level=2&answer=7" => {
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have 45s left to wait."
+        }
+        _ => "That's not the right answer.",
+    };
+    (200, answer_page(text))
+}
+
+#[test]
+fn submit_answers() {
+    let server = MockServer::start(puzzle_site);
+    let answers = TempDir::new("submit");
+    let client = Client::new(&server.url, Some("abc123".to_string()));
+    let day = select(&["2025/11".parse().unwrap()]).unwrap()[0];
+
+    assert_eq!(
+        submit(&client, &day, 1, "100", &answers.0).unwrap(),
+        Response::Verdict(Verdict::TooHigh)
+    );
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].headers["cookie"], "session=abc123");
+    assert_eq!(
+        requests[0].headers["content-type"],
+        "application/x-www-form-urlencoded"
+    );
+
+    // The wrong answer started a cooldown, so nothing can be submitted until it's over.
+    let path = day.submissions_path(&answers.0);
+    let mut submissions = Submissions::load(&path).unwrap();
+    assert!(submissions.wait_until.is_some());
+    assert!(submissions.check(1, "50", u64::MAX).is_ok());
+    assert!(submit(&client, &day, 1, "50", &answers.0).is_err());
+    submissions.wait_until = None;
+    submissions.save(&path).unwrap();
+
+    // Known-wrong answers are never sent.
+    assert!(submit(&client, &day, 1, "100", &answers.0).is_err());
+    assert!(submit(&client, &day, 1, "101", &answers.0).is_err());
+    assert_eq!(server.requests().len(), 1);
+
+    assert_eq!(
+        submit(&client, &day, 1, "50", &answers.0).unwrap(),
+        Response::Verdict(Verdict::Correct)
+    );
+    let known = KnownAnswers::load(&day.answers_path(&answers.0))
+        .unwrap()
+        .unwrap();
+    assert_eq!(known.part1.as_deref(), Some("50"));
+    assert!(submit(&client, &day, 1, "49", &answers.0).is_err());
+
+    assert_eq!(
+        submit(&client, &day, 2, "7", &answers.0).unwrap(),
+        Response::Wait(Duration::from_secs(45))
+    );
+    let submissions = Submissions::load(&path).unwrap();
+    assert!(submissions.part2.is_empty());
+    assert!(submissions.wait_until.is_some());
+    assert_eq!(server.requests().len(), 3);
+}
@@ -0,0 +1,36 @@
+//! Tests running a day's binary on its targets as watch mode does.
+
+mod common;
+
+use aoc::{
+    select,
+    watch::{run_target, targets},
+};
+use common::TempDir;
+use std::{
+    fs::{copy, create_dir_all},
+    path::PathBuf,
+    process,
+};
+
+#[test]
+fn relative_inputs() {
+    // The binary runs in the day's own directory, so this checks the input is still found from
+    // there when the inputs directory is relative to ours.
+    let inputs = TempDir(PathBuf::from(format!("watch-inputs-{}", process::id())));
+    let day = select(&["2024/1".parse().unwrap()]).unwrap()[0];
+    let input = day.input_path(&inputs.0);
+    create_dir_all(input.parent().unwrap()).unwrap();
+    copy(day.example_path("example"), &input).unwrap();
+
+    let targets = targets(&day, &inputs.0).unwrap();
+    let [example, input] = targets.as_slice() else {
+        panic!("Expected the example and input, got {:?}", targets);
+    };
+    assert_eq!(input.name, "input");
+    assert!(input.input.is_relative());
+    assert_eq!(
+        run_target(&day, input).unwrap(),
+        run_target(&day, example).unwrap()
+    );
+}
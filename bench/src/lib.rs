@@ -0,0 +1,280 @@
+use aoc::Day;
+use eyre::Report;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use utils::{
+    args::Params,
+    solution::{Answer, Parts},
+};
+
+/// Summary statistics for a set of timings, all in nanoseconds.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+    pub max: u64,
+    pub std_dev: u64,
+}
+
+impl Stats {
+    /// Calculates statistics for the given timings, or returns `None` if there are none.
+    pub fn new(timings: &[Duration]) -> Option<Self> {
+        if timings.is_empty() {
+            return None;
+        }
+        let mut nanos = timings
+            .iter()
+            .map(|timing| timing.as_nanos() as u64)
+            .collect::<Vec<_>>();
+        nanos.sort_unstable();
+        let samples = nanos.len();
+        let median = if samples.is_multiple_of(2) {
+            (nanos[samples / 2 - 1] + nanos[samples / 2]) / 2
+        } else {
+            nanos[samples / 2]
+        };
+        let mean = nanos.iter().sum::<u64>() as f64 / samples as f64;
+        let variance = nanos
+            .iter()
+            .map(|&n| (n as f64 - mean).powi(2))
+            .sum::<f64>()
+            / samples as f64;
+        Some(Self {
+            samples,
+            min: nanos[0],
+            median,
+            mean: mean.round() as u64,
+            max: nanos[samples - 1],
+            std_dev: variance.sqrt().round() as u64,
+        })
+    }
+}
+
+/// The timings for each step of one day.
+///
+/// A part is `None` if it hasn't been solved yet.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl DayResult {
+    /// Returns the statistics for each step which was timed, with the step's name.
+    pub fn steps(&self) -> impl Iterator<Item = (&'static str, &Stats)> {
+        [
+            ("parse", Some(&self.parse)),
+            ("part 1", self.part1.as_ref()),
+            ("part 2", self.part2.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(step, stats)| Some((step, stats?)))
+    }
+}
+
+/// The results of a whole benchmark run, as written to and read from JSON.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Results {
+    pub days: Vec<DayResult>,
+}
+
+/// Solves the given day's input `warm_up` times without timing it, then `samples` times
+/// recording how long parsing and each part take.
+///
+/// The puzzle's default parameters are always used.
+pub fn bench_day(
+    day: &Day,
+    input: &str,
+    warm_up: usize,
+    samples: usize,
+) -> Result<DayResult, Report> {
+    let params = Params::default();
+    for _ in 0..warm_up {
+        (day.solve)(input, &params, Parts::Both)?;
+    }
+    let mut parse = Vec::with_capacity(samples);
+    let mut part1 = Vec::with_capacity(samples);
+    let mut part2 = Vec::with_capacity(samples);
+    for _ in 0..samples.max(1) {
+        let answers = (day.solve)(input, &params, Parts::Both)?;
+        parse.push(answers.parse_time);
+        part1.extend(solved_time(answers.part1));
+        part2.extend(solved_time(answers.part2));
+    }
+    Ok(DayResult {
+        year: day.year,
+        day: day.day,
+        parse: Stats::new(&parse).unwrap(),
+        part1: Stats::new(&part1),
+        part2: Stats::new(&part2),
+    })
+}
+
+fn solved_time(answer: Option<Answer>) -> Option<Duration> {
+    answer
+        .filter(|answer| answer.value.is_some())
+        .map(|answer| answer.time)
+}
+
+/// The change in median time of one step between two benchmark runs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Change {
+    pub year: u16,
+    pub day: u8,
+    pub step: &'static str,
+    pub before: u64,
+    pub after: u64,
+}
+
+impl Change {
+    /// Returns the new median time as a proportion of the old one.
+    pub fn ratio(&self) -> f64 {
+        self.after as f64 / self.before.max(1) as f64
+    }
+}
+
+/// Compares the median times of each step which appears in both runs.
+pub fn compare(before: &Results, after: &Results) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for after_day in &after.days {
+        let Some(before_day) = before
+            .days
+            .iter()
+            .find(|day| day.year == after_day.year && day.day == after_day.day)
+        else {
+            continue;
+        };
+        for (step, after_stats) in after_day.steps() {
+            if let Some((_, before_stats)) = before_day.steps().find(|(s, _)| *s == step) {
+                changes.push(Change {
+                    year: after_day.year,
+                    day: after_day.day,
+                    step,
+                    before: before_stats.median,
+                    after: after_stats.median,
+                });
+            }
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            samples: 1,
+            min: median,
+            median,
+            mean: median,
+            max: median,
+            std_dev: 0,
+        }
+    }
+
+    #[test]
+    fn stats_empty() {
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn stats_summary() {
+        let timings = [4, 1, 3, 2].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::new(&timings),
+            Some(Stats {
+                samples: 4,
+                min: 1,
+                median: 2,
+                mean: 3,
+                max: 4,
+                std_dev: 1,
+            })
+        );
+        let timings = [5, 1, 3].map(Duration::from_nanos);
+        assert_eq!(Stats::new(&timings).unwrap().median, 3);
+    }
+
+    #[test]
+    fn bench_example() {
+        let day = aoc::select(&["2024/1".parse().unwrap()]).unwrap()[0];
+        let result = bench_day(&day, "3   4\n4   3\n", 1, 3).unwrap();
+        assert_eq!(result.parse.samples, 3);
+        assert_eq!(result.part1.unwrap().samples, 3);
+        assert_eq!(result.part2.unwrap().samples, 3);
+    }
+
+    #[test]
+    fn results_round_trip() {
+        let results = Results {
+            days: vec![DayResult {
+                year: 2024,
+                day: 6,
+                parse: stats(10),
+                part1: Some(stats(20)),
+                part2: None,
+            }],
+        };
+        let json = serde_json::to_string(&results).unwrap();
+        assert_eq!(serde_json::from_str::<Results>(&json).unwrap(), results);
+    }
+
+    #[test]
+    fn compare_runs() {
+        let before = Results {
+            days: vec![
+                DayResult {
+                    year: 2024,
+                    day: 6,
+                    parse: stats(10),
+                    part1: Some(stats(20)),
+                    part2: None,
+                },
+                DayResult {
+                    year: 2024,
+                    day: 7,
+                    parse: stats(10),
+                    part1: None,
+                    part2: None,
+                },
+            ],
+        };
+        let after = Results {
+            days: vec![DayResult {
+                year: 2024,
+                day: 6,
+                parse: stats(10),
+                part1: Some(stats(30)),
+                part2: Some(stats(5)),
+            }],
+        };
+        let changes = compare(&before, &after);
+        assert_eq!(
+            changes,
+            vec![
+                Change {
+                    year: 2024,
+                    day: 6,
+                    step: "parse",
+                    before: 10,
+                    after: 10,
+                },
+                Change {
+                    year: 2024,
+                    day: 6,
+                    step: "part 1",
+                    before: 20,
+                    after: 30,
+                },
+            ]
+        );
+        assert_eq!(changes[1].ratio(), 1.5);
+    }
+}
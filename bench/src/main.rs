@@ -0,0 +1,139 @@
+use aoc::{Selection, select};
+use bench::{Results, Stats, bench_day, compare};
+use eyre::{OptionExt, Report, WrapErr, bail};
+use std::{
+    env,
+    fs::{read_to_string, write},
+    path::PathBuf,
+    time::Duration,
+};
+use utils::log::{Level, set_level};
+
+const USAGE: &str = "\
+Usage: bench [--inputs <dir>] [--warm-up <runs>] [--samples <runs>] [--output <file>]
+             [--compare <file>] [--threshold <percent>] [-v | --trace] [YEAR[/DAY]...]";
+
+fn main() -> Result<(), Report> {
+    let options = Options::parse(env::args().skip(1))?;
+    set_level(options.level);
+
+    let mut results = Results::default();
+    for day in select(&options.selections)? {
+        let path = day.input_path(&options.inputs);
+        let Ok(input) = read_to_string(&path) else {
+            eprintln!("{}: no input at {}", day, path.display());
+            continue;
+        };
+        let result = bench_day(&day, &input, options.warm_up, options.samples)
+            .wrap_err_with(|| format!("Running {}", day))?;
+        for (step, stats) in result.steps() {
+            println!("{} {:6}  {}", day, step, format_stats(stats));
+        }
+        results.days.push(result);
+    }
+
+    if let Some(output) = &options.output {
+        write(output, serde_json::to_string_pretty(&results)?)
+            .wrap_err_with(|| format!("Writing {}", output.display()))?;
+    }
+
+    if let Some(previous) = &options.compare {
+        let previous = serde_json::from_str::<Results>(
+            &read_to_string(previous)
+                .wrap_err_with(|| format!("Reading {}", previous.display()))?,
+        )?;
+        let threshold = options.threshold / 100.0;
+        let mut regressions = 0;
+        println!();
+        for change in compare(&previous, &results) {
+            let ratio = change.ratio();
+            let verdict = if ratio > 1.0 + threshold {
+                regressions += 1;
+                "slower"
+            } else if ratio < 1.0 - threshold {
+                "faster"
+            } else {
+                continue;
+            };
+            println!(
+                "{}/{:02} {:6}  {} -> {}  ({:+.1}%, {})",
+                change.year,
+                change.day,
+                change.step,
+                format_nanos(change.before),
+                format_nanos(change.after),
+                (ratio - 1.0) * 100.0,
+                verdict
+            );
+        }
+        if regressions > 0 {
+            bail!("{} steps got slower", regressions);
+        }
+    }
+    Ok(())
+}
+
+/// Command-line options for the benchmarks.
+#[derive(Debug)]
+struct Options {
+    inputs: PathBuf,
+    warm_up: usize,
+    samples: usize,
+    output: Option<PathBuf>,
+    compare: Option<PathBuf>,
+    /// The percentage change in median time to report.
+    threshold: f64,
+    level: Level,
+    selections: Vec<Selection>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Report> {
+        let mut options = Self {
+            inputs: PathBuf::from("inputs"),
+            warm_up: 1,
+            samples: 10,
+            output: None,
+            compare: None,
+            threshold: 10.0,
+            level: Level::Quiet,
+            selections: Vec::new(),
+        };
+        while let Some(arg) = args.next() {
+            if let Some(level) = Level::from_switch(&arg) {
+                options.level = level;
+                continue;
+            }
+            match arg.as_str() {
+                "--inputs" => options.inputs = args.next().ok_or_eyre(USAGE)?.into(),
+                "--warm-up" => options.warm_up = args.next().ok_or_eyre(USAGE)?.parse()?,
+                "--samples" => options.samples = args.next().ok_or_eyre(USAGE)?.parse()?,
+                "--output" => options.output = Some(args.next().ok_or_eyre(USAGE)?.into()),
+                "--compare" => options.compare = Some(args.next().ok_or_eyre(USAGE)?.into()),
+                "--threshold" => options.threshold = args.next().ok_or_eyre(USAGE)?.parse()?,
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    std::process::exit(0);
+                }
+                _ if arg.starts_with('-') => bail!("Unknown option '{}'\n{}", arg, USAGE),
+                _ => options.selections.push(arg.parse()?),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "median {:>9}  mean {:>9} ± {:>9}  min {:>9}  max {:>9}",
+        format_nanos(stats.median),
+        format_nanos(stats.mean),
+        format_nanos(stats.std_dev),
+        format_nanos(stats.min),
+        format_nanos(stats.max)
+    )
+}
+
+fn format_nanos(nanos: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos))
+}
@@ -0,0 +1,12 @@
+//! Feeds arbitrary bytes to `parse_chargrid`, which should return an error rather than panic.
+//!
+//! Run with `cargo fuzz run parse_chargrid` from the workspace root.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use utils::parse_chargrid;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_chargrid(data);
+});
@@ -0,0 +1,22 @@
+//! Feeds arbitrary input to every day's parser, which should return an error rather than panic.
+//!
+//! Run with `cargo fuzz run parse_day` from the workspace root.
+
+#![no_main]
+
+use aoc::DAYS;
+use libfuzzer_sys::fuzz_target;
+use std::str;
+use utils::args::Params;
+
+// The first byte picks the day, and the rest is its input.
+fuzz_target!(|data: &[u8]| {
+    let Some((&day, input)) = data.split_first() else {
+        return;
+    };
+    let Ok(input) = str::from_utf8(input) else {
+        return;
+    };
+    let day = &DAYS[usize::from(day) % DAYS.len()];
+    let _ = (day.parse)(input, &Params::default());
+});
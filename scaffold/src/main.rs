@@ -0,0 +1,292 @@
+//! Creates a new day crate from the templates, and adds it to the workspace and the runner.
+
+use eyre::{OptionExt, Report, WrapErr, bail, eyre};
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+const USAGE: &str = "\
+Usage: scaffold [--root <dir>] YEAR/DAY";
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.template");
+
+fn main() -> Result<(), Report> {
+    let options = Options::parse(env::args().skip(1))?;
+    for path in scaffold(&options.root, options.year, options.day)? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// Command-line options for the generator.
+#[derive(Debug)]
+struct Options {
+    /// The root of the workspace.
+    root: PathBuf,
+    year: u16,
+    day: u8,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Report> {
+        let mut root = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .to_owned();
+        let mut puzzle = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--root" => root = args.next().ok_or_eyre(USAGE)?.into(),
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    std::process::exit(0);
+                }
+                _ if arg.starts_with('-') || puzzle.is_some() => {
+                    bail!("Unexpected argument '{}'\n{}", arg, USAGE)
+                }
+                _ => puzzle = Some(parse_puzzle(&arg)?),
+            }
+        }
+        let (year, day) = puzzle.ok_or_eyre(USAGE)?;
+        Ok(Self { root, year, day })
+    }
+}
+
+/// Parses a puzzle of the form `YYYY/DAY`.
+fn parse_puzzle(s: &str) -> Result<(u16, u8), Report> {
+    let (year, day) = s
+        .split_once('/')
+        .ok_or_else(|| eyre!("Puzzle '{}' should be YEAR/DAY", s))?;
+    let year = year.parse()?;
+    let day = day.parse()?;
+    if !(1000..=9999).contains(&year) || !(1..=25).contains(&day) {
+        bail!("Invalid puzzle '{}'", s);
+    }
+    Ok((year, day))
+}
+
+/// Creates the crate for the given day within the workspace at `root`, and adds it to the
+/// workspace members and the runner's dependencies if necessary.
+///
+/// Returns the paths of all the files written.
+fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, Report> {
+    let dir = root.join(year.to_string()).join(format!("day{}", day));
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+    let fill = |template: &str| {
+        template
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+    };
+    let files = [
+        (dir.join("Cargo.toml"), fill(CARGO_TEMPLATE)),
+        (dir.join("src/lib.rs"), fill(LIB_TEMPLATE)),
+        (dir.join("src/main.rs"), fill(MAIN_TEMPLATE)),
+        (dir.join("examples/example.txt"), String::new()),
+        (dir.join("examples/example.toml"), String::new()),
+    ];
+    let mut written = Vec::new();
+    for (path, contents) in files {
+        create_dir_all(path.parent().unwrap())?;
+        write(&path, contents).wrap_err_with(|| format!("Writing {}", path.display()))?;
+        written.push(path);
+    }
+
+    let workspace = root.join("Cargo.toml");
+    if update_file(&workspace, |manifest| add_workspace_member(manifest, year))? {
+        written.push(workspace);
+    }
+    let runner = root.join("aoc/Cargo.toml");
+    if update_file(&runner, |manifest| {
+        add_runner_dependency(manifest, year, day)
+    })? {
+        written.push(runner);
+    }
+    Ok(written)
+}
+
+/// Rewrites the given file with the result of `update`, unless that is `None`. Returns whether
+/// the file was changed.
+fn update_file(
+    path: &Path,
+    update: impl FnOnce(&str) -> Result<Option<String>, Report>,
+) -> Result<bool, Report> {
+    let contents = read_to_string(path).wrap_err_with(|| format!("Reading {}", path.display()))?;
+    match update(&contents)? {
+        Some(contents) => {
+            write(path, contents).wrap_err_with(|| format!("Writing {}", path.display()))?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Adds the glob for the given year to the workspace members, keeping them sorted.
+///
+/// Returns `None` if the year is already a member.
+fn add_workspace_member(manifest: &str, year: u16) -> Result<Option<String>, Report> {
+    let member = format!("{}/*", year);
+    let start = manifest
+        .find("members = [")
+        .ok_or_eyre("Workspace members not found")?
+        + "members = [".len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or_eyre("Workspace members not found")?;
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<_>>();
+    if members.contains(&member.as_str()) {
+        return Ok(None);
+    }
+    members.push(&member);
+    members.sort();
+    let members = members
+        .iter()
+        .map(|member| format!("{:?}", member))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok(Some(format!(
+        "{}{}{}",
+        &manifest[..start],
+        members,
+        &manifest[end..]
+    )))
+}
+
+/// Adds a dependency on the given day to the runner's manifest, in order of year and day after
+/// the other days.
+///
+/// Returns `None` if the dependency is already there.
+fn add_runner_dependency(manifest: &str, year: u16, day: u8) -> Result<Option<String>, Report> {
+    let name = format!("aoc-{}-day{}", year, day);
+    let line = format!("{} = {{ path = \"../{}/day{}\" }}", name, year, day);
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+    let day_key = |line: &str| {
+        let (year, day) = line
+            .split_once(' ')?
+            .0
+            .strip_prefix("aoc-")?
+            .split_once("-day")?;
+        Some((year.parse::<u16>().ok()?, day.parse::<u8>().ok()?))
+    };
+    if lines.iter().any(|line| day_key(line) == Some((year, day))) {
+        return Ok(None);
+    }
+    let index = match lines
+        .iter()
+        .position(|line| day_key(line) > Some((year, day)))
+    {
+        Some(index) => index,
+        None => {
+            lines
+                .iter()
+                .rposition(|line| day_key(line).is_some())
+                .ok_or_eyre("No day dependencies found in the runner's manifest")?
+                + 1
+        }
+    };
+    lines.insert(index, &line);
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::remove_dir_all;
+
+    const RUNNER_MANIFEST: &str = "\
+[package]
+name = \"aoc\"
+
+[dependencies]
+aoc-2024-day1 = { path = \"../2024/day1\" }
+aoc-2024-day10 = { path = \"../2024/day10\" }
+aoc-2025-day1 = { path = \"../2025/day1\" }
+eyre = \"0.6.12\"
+";
+
+    #[test]
+    fn parse_puzzles() {
+        assert_eq!(parse_puzzle("2026/3").unwrap(), (2026, 3));
+        assert!(parse_puzzle("2026").is_err());
+        assert!(parse_puzzle("2026/26").is_err());
+        assert!(parse_puzzle("26/1").is_err());
+    }
+
+    #[test]
+    fn workspace_members() {
+        let manifest =
+            "[workspace]\nmembers = [\"2024/*\", \"aoc\", \"utils\"]\nresolver = \"2\"\n";
+        assert_eq!(
+            add_workspace_member(manifest, 2023).unwrap().unwrap(),
+            "[workspace]\nmembers = [\"2023/*\", \"2024/*\", \"aoc\", \"utils\"]\nresolver = \"2\"\n"
+        );
+        assert_eq!(add_workspace_member(manifest, 2024).unwrap(), None);
+    }
+
+    #[test]
+    fn runner_dependencies() {
+        let manifest = add_runner_dependency(RUNNER_MANIFEST, 2024, 2)
+            .unwrap()
+            .unwrap();
+        assert!(manifest.contains(
+            "aoc-2024-day1 = { path = \"../2024/day1\" }\n\
+             aoc-2024-day2 = { path = \"../2024/day2\" }\n\
+             aoc-2024-day10"
+        ));
+        let manifest = add_runner_dependency(RUNNER_MANIFEST, 2026, 1)
+            .unwrap()
+            .unwrap();
+        assert!(manifest.contains(
+            "aoc-2025-day1 = { path = \"../2025/day1\" }\n\
+             aoc-2026-day1 = { path = \"../2026/day1\" }\n\
+             eyre"
+        ));
+        assert_eq!(
+            add_runner_dependency(RUNNER_MANIFEST, 2024, 10).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn scaffold_new_year() {
+        let root = env::temp_dir().join(format!("scaffold-test-{}", std::process::id()));
+        create_dir_all(root.join("aoc")).unwrap();
+        write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"2025/*\", \"aoc\"]\n",
+        )
+        .unwrap();
+        write(root.join("aoc/Cargo.toml"), RUNNER_MANIFEST).unwrap();
+
+        let written = scaffold(&root, 2026, 4).unwrap();
+        assert_eq!(written.len(), 7);
+        let lib = read_to_string(root.join("2026/day4/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day4 {"));
+        assert!(lib.contains("example_tests!(Day4, example);"));
+        let main = read_to_string(root.join("2026/day4/src/main.rs")).unwrap();
+        assert!(main.starts_with("use aoc_2026_day4::Day4;"));
+        assert!(
+            read_to_string(root.join("Cargo.toml"))
+                .unwrap()
+                .contains("\"2026/*\"")
+        );
+        assert!(
+            read_to_string(root.join("aoc/Cargo.toml"))
+                .unwrap()
+                .contains("aoc-2026-day4")
+        );
+        assert!(scaffold(&root, 2026, 4).is_err());
+
+        remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+//! A global allocator which counts allocations and tracks peak heap usage.
+//!
+//! This is only built with the `count-allocations` feature, which replaces the global allocator
+//! of any binary using this crate.
+//!
+//! Cargo unifies features across a build, so enabling the feature for any crate, such as with
+//! `cargo run -p aoc --features count-allocations`, installs this allocator in every binary and
+//! test built by the same command. Build with it only for the crates being measured.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator, counting every allocation.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            // Only growing counts as an allocation, as shrinking frees memory.
+            if new_size > layout.size() {
+                record_allocation(new_size - layout.size());
+            } else {
+                CURRENT_BYTES.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+/// The heap allocations made while running some code.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct AllocationStats {
+    /// The number of allocations and reallocations which grew an allocation.
+    pub allocations: usize,
+    /// The total number of bytes allocated, including any which were freed again.
+    pub allocated_bytes: usize,
+    /// The largest amount of extra heap in use at any one time.
+    pub peak_bytes: usize,
+}
+
+/// Runs the given function and returns its result along with the heap allocations it made.
+///
+/// Peak usage is relative to the heap in use when the function was called. Allocations made by
+/// other threads at the same time are counted too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocationStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(start_bytes, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocationStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+        peak_bytes: PEAK_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(start_bytes),
+    };
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[test]
+    fn measure_vec() {
+        let (_, stats) = measure(|| {
+            let small = black_box(vec![0u8; 1000]);
+            drop(small);
+            black_box(vec![0u64; 1000])
+        });
+        assert!(stats.allocations >= 2);
+        assert!(stats.allocated_bytes >= 9000);
+        assert!(stats.peak_bytes >= 8000);
+    }
+
+    #[test]
+    fn shrinking_is_not_an_allocation() {
+        // Other tests may allocate at the same time, so only one attempt needs to see no
+        // allocations.
+        let shrinks_without_allocating = (0..10).any(|_| {
+            let mut large = black_box(Vec::<u8>::with_capacity(1000));
+            large.push(1);
+            let (_, stats) = measure(|| large.shrink_to_fit());
+            stats == AllocationStats::default()
+        });
+        assert!(shrinks_without_allocating);
+    }
+}
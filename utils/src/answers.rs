@@ -0,0 +1,332 @@
+use eyre::{Report, WrapErr, bail, eyre};
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::{create_dir_all, read_to_string, write},
+    io::ErrorKind,
+    path::Path,
+};
+use toml::{Table, Value};
+
+use crate::{
+    args::Params,
+    solution::{Answer, Answers, Parts, Solution, solve},
+};
+
+/// The accepted answers to a day's puzzle, as stored in `answers/YYYY/DD.toml`, or the expected
+/// answers for an example.
+///
+/// The file has a `part1` and `part2` key, either of which may be missing if that part hasn't
+/// been answered yet. Answers may be written as strings or integers. An optional `[params]`
+/// table gives the parameters to solve the input with, which is mostly useful for examples.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct KnownAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub params: Params,
+}
+
+impl KnownAnswers {
+    /// Loads the known answers from the given file, or returns `None` if it doesn't exist.
+    pub fn load(path: &Path) -> Result<Option<Self>, Report> {
+        match read_to_string(path) {
+            Ok(contents) => Self::parse(&contents)
+                .wrap_err_with(|| format!("Parsing {}", path.display()))
+                .map(Some),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(eyre!("Reading {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Parses the contents of a known answers file.
+    pub fn parse(contents: &str) -> Result<Self, Report> {
+        let mut table = contents.parse::<Table>()?;
+        let mut params = Params::default();
+        match table.remove("params") {
+            Some(Value::Table(values)) => {
+                for (key, value) in values {
+                    params.insert(key, answer_from_value(value)?);
+                }
+            }
+            Some(value) => bail!("Params {} should be a table", value),
+            None => {}
+        }
+        let answers = Self {
+            part1: table.remove("part1").map(answer_from_value).transpose()?,
+            part2: table.remove("part2").map(answer_from_value).transpose()?,
+            params,
+        };
+        if let Some(key) = table.keys().next() {
+            bail!("Unexpected key '{}'", key);
+        }
+        Ok(answers)
+    }
+
+    /// Returns which parts have a known answer, or `None` if neither does.
+    pub fn parts(&self) -> Option<Parts> {
+        match (&self.part1, &self.part2) {
+            (Some(_), Some(_)) => Some(Parts::Both),
+            (Some(_), None) => Some(Parts::Part1),
+            (None, Some(_)) => Some(Parts::Part2),
+            (None, None) => None,
+        }
+    }
+
+    /// Writes the known answers to the given file, creating its directory if necessary.
+    pub fn save(&self, path: &Path) -> Result<(), Report> {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        write(path, self.to_string()).wrap_err_with(|| format!("Writing {}", path.display()))
+    }
+
+    /// Fills in any answers which aren't known yet from the given answers, and returns whether
+    /// anything was added.
+    pub fn record(&mut self, answers: &Answers) -> bool {
+        let mut changed = false;
+        for (known, answer) in [
+            (&mut self.part1, &answers.part1),
+            (&mut self.part2, &answers.part2),
+        ] {
+            if known.is_none()
+                && let Some(value) = answer.as_ref().and_then(|answer| answer.value.as_ref())
+            {
+                *known = Some(value.clone());
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Checks the given answers against the known answers, returning an error describing every
+    /// part which doesn't match.
+    ///
+    /// Parts which weren't run or which have no known answer are ignored.
+    pub fn check(&self, answers: &Answers) -> Result<(), Report> {
+        let mismatches = [
+            ("Part 1", &self.part1, &answers.part1),
+            ("Part 2", &self.part2, &answers.part2),
+        ]
+        .into_iter()
+        .filter_map(|(part, known, answer)| match (known, answer) {
+            (Some(known), Some(Answer { value, .. })) if value.as_ref() != Some(known) => {
+                Some(format!(
+                    "{} answer was {} but expected {}",
+                    part,
+                    value.as_deref().unwrap_or("not solved"),
+                    known
+                ))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            bail!(mismatches.join("\n"))
+        }
+    }
+}
+
+impl Display for KnownAnswers {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (key, answer) in [("part1", &self.part1), ("part2", &self.part2)] {
+            if let Some(answer) = answer {
+                writeln!(f, "{} = {}", key, Value::String(answer.clone()))?;
+            }
+        }
+        if !self.params.is_empty() {
+            writeln!(f, "\n[params]")?;
+            for (key, value) in self.params.iter() {
+                writeln!(f, "{} = {}", key, Value::String(value.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Solves the given example input and checks the answers against the expected answers, which are
+/// in the same format as a known answers file.
+///
+/// Only the parts with an expected answer are solved, using the example's parameters. If there
+/// are no expected answers the input is just parsed.
+pub fn check_example<S: Solution>(input: &str, expected: &str) -> Result<(), Report> {
+    let expected = KnownAnswers::parse(expected).wrap_err("Parsing expected answers")?;
+    match expected.parts() {
+        Some(parts) => expected.check(&solve::<S>(input, &expected.params, parts)?),
+        None => S::parse_with_params(input, &expected.params).map(|_| ()),
+    }
+}
+
+/// Generates a test for each of the named example fixtures, which checks the answers for the
+/// input in `examples/NAME.txt` against the expected answers in `examples/NAME.toml`.
+///
+/// ```ignore
+/// example_tests!(Day15, small, large);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, $($name:ident),+ $(,)?) => {
+        mod examples {
+            use super::*;
+
+            $(
+                #[test]
+                fn $name() {
+                    $crate::answers::check_example::<$solution>(
+                        include_str!(concat!(
+                            env!("CARGO_MANIFEST_DIR"),
+                            "/examples/",
+                            stringify!($name),
+                            ".txt"
+                        )),
+                        include_str!(concat!(
+                            env!("CARGO_MANIFEST_DIR"),
+                            "/examples/",
+                            stringify!($name),
+                            ".toml"
+                        )),
+                    )
+                    .unwrap();
+                }
+            )+
+        }
+    };
+}
+
+fn answer_from_value(value: Value) -> Result<String, Report> {
+    match value {
+        Value::String(answer) => Ok(answer),
+        Value::Integer(answer) => Ok(answer.to_string()),
+        value => bail!("Value {} should be a string or integer", value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::not_solved;
+    use std::{fmt::Display, time::Duration};
+
+    fn answers(part1: Option<&str>, part2: Option<&str>) -> Answers {
+        let answer = |value: Option<&str>| {
+            Some(Answer {
+                value: value.map(String::from),
+                time: Duration::ZERO,
+            })
+        };
+        Answers {
+            parse_time: Duration::ZERO,
+            part1: answer(part1),
+            part2: answer(part2),
+        }
+    }
+
+    #[test]
+    fn parse_known_answers() {
+        assert_eq!(
+            KnownAnswers::parse("part1 = 42\npart2 = \"4,6,3,5\"\n").unwrap(),
+            KnownAnswers {
+                part1: Some("42".to_string()),
+                part2: Some("4,6,3,5".to_string()),
+                params: Params::default(),
+            }
+        );
+        assert_eq!(
+            KnownAnswers::parse("part1 = 42\n").unwrap(),
+            KnownAnswers {
+                part1: Some("42".to_string()),
+                part2: None,
+                params: Params::default(),
+            }
+        );
+        let known =
+            KnownAnswers::parse("part1 = 12\n[params]\nwidth = 11\nname = \"x\"\n").unwrap();
+        assert_eq!(
+            known.params.iter().collect::<Vec<_>>(),
+            [("name", "x"), ("width", "11")]
+        );
+        assert!(KnownAnswers::parse("params = 11\n").is_err());
+        assert!(KnownAnswers::parse("part3 = 42\n").is_err());
+        assert!(KnownAnswers::parse("part1 = 4.2\n").is_err());
+    }
+
+    #[test]
+    fn format_round_trip() {
+        let mut known = KnownAnswers {
+            part1: Some("42".to_string()),
+            part2: Some("a \"quoted\" answer".to_string()),
+            params: Params::default(),
+        };
+        assert_eq!(KnownAnswers::parse(&known.to_string()).unwrap(), known);
+        known.params.insert("width", "11");
+        assert_eq!(KnownAnswers::parse(&known.to_string()).unwrap(), known);
+    }
+
+    #[test]
+    fn check_answers() {
+        let known = KnownAnswers {
+            part1: Some("42".to_string()),
+            part2: None,
+            params: Params::default(),
+        };
+        assert!(known.check(&answers(Some("42"), Some("7"))).is_ok());
+        assert_eq!(
+            known
+                .check(&answers(Some("41"), None))
+                .unwrap_err()
+                .to_string(),
+            "Part 1 answer was 41 but expected 42"
+        );
+        assert!(known.check(&answers(None, None)).is_err());
+    }
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, Report> {
+            Ok(input
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<impl Display, Report> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part2(_input: &Self::Input) -> Result<impl Display, Report> {
+            not_solved()
+        }
+    }
+
+    #[test]
+    fn check_examples() {
+        check_example::<Sum>("1 2 3", "part1 = 6").unwrap();
+        check_example::<Sum>("1 2 3", "").unwrap();
+        assert!(check_example::<Sum>("1 2 3", "part1 = 7").is_err());
+        assert!(check_example::<Sum>("1 2 3", "part2 = 6").is_err());
+        assert!(check_example::<Sum>("1 x 3", "").is_err());
+        assert!(check_example::<Sum>("1 2 3", "part1 = 6\n[params]\nscale = 2").is_err());
+    }
+
+    #[test]
+    fn record_answers() {
+        let mut known = KnownAnswers {
+            part1: Some("42".to_string()),
+            part2: None,
+            params: Params::default(),
+        };
+        assert!(!known.record(&answers(Some("41"), None)));
+        assert!(known.record(&answers(Some("41"), Some("7"))));
+        assert_eq!(
+            known,
+            KnownAnswers {
+                part1: Some("42".to_string()),
+                part2: Some("7".to_string()),
+                params: Params::default(),
+            }
+        );
+    }
+}
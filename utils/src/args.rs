@@ -0,0 +1,239 @@
+use crate::{
+    budget::{Budget, parse_seconds},
+    log::Level,
+    solution::Parts,
+};
+use eyre::{Report, WrapErr, bail, eyre};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::read_to_string,
+    io::{self, stdin},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Returns the usage message for a day binary with the given name.
+pub fn usage(program: &str) -> String {
+    let indent = " ".repeat(program.len());
+    format!(
+        "\
+Usage: {program} [--input <file>] [--part 1|2] [--param <key>=<value>]... [--json]
+       {indent} [-v | --trace] [--timeout <seconds>] [--max-steps <n>] [< input]
+
+--timeout and --max-steps stop a solution with an error once it has run for too long, if it
+reports its progress."
+    )
+}
+
+/// Named puzzle constants, such as the size of a grid, which can be set with
+/// `--param key=value`.
+///
+/// The examples often use different values to the real puzzle, so a solution takes them from here
+/// with its usual value as the default.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// Sets the given parameter, replacing any previous value.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    /// Sets a parameter from a `key=value` argument.
+    pub fn insert_arg(&mut self, arg: &str) -> Result<(), Report> {
+        let (key, value) = arg
+            .split_once('=')
+            .ok_or_else(|| eyre!("Parameter '{}' should be key=value", arg))?;
+        self.insert(key.trim(), value.trim());
+        Ok(())
+    }
+
+    /// Sets all the parameters from `other`, replacing any which are already set.
+    pub fn extend(&mut self, other: &Params) {
+        self.0.extend(other.0.clone());
+    }
+
+    /// Returns the value of the given parameter, or `default` if it isn't set.
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T, Report>
+    where
+        T::Err: Display,
+    {
+        match self.0.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|e| eyre!("Invalid value '{}' for parameter '{}': {}", value, key, e)),
+            None => Ok(default),
+        }
+    }
+
+    /// Returns an error if any parameter isn't one of the given names.
+    pub fn check(&self, names: &[&str]) -> Result<(), Report> {
+        if let Some(key) = self.0.keys().find(|key| !names.contains(&key.as_str())) {
+            if names.is_empty() {
+                bail!("Unknown parameter '{}', this day has no parameters", key);
+            }
+            bail!(
+                "Unknown parameter '{}', expected one of {}",
+                key,
+                names.join(", ")
+            );
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the parameters in order of their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+/// The command-line arguments for a day binary.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Args {
+    /// The file to read the input from, or `None` for stdin.
+    pub input: Option<PathBuf>,
+    pub parts: Parts,
+    pub params: Params,
+    /// Whether to print the answers as JSON.
+    pub json: bool,
+    pub level: Level,
+    /// The limits on how long the solution may run.
+    pub budget: Budget,
+}
+
+impl Args {
+    /// Parses the given command line, which starts with the path of the binary as from
+    /// `env::args`.
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Report> {
+        let path = args.next().unwrap_or_default();
+        let program = Path::new(&path)
+            .file_name()
+            .map_or("day".into(), |name| name.to_string_lossy());
+        let missing = || eyre!("{}", usage(&program));
+        let mut parsed = Self::default();
+        while let Some(arg) = args.next() {
+            if let Some(level) = Level::from_switch(&arg) {
+                parsed.level = level;
+                continue;
+            }
+            match arg.as_str() {
+                "--input" => parsed.input = Some(args.next().ok_or_else(missing)?.into()),
+                "--part" => parsed.parts = args.next().ok_or_else(missing)?.parse()?,
+                "--param" => parsed
+                    .params
+                    .insert_arg(&args.next().ok_or_else(missing)?)?,
+                "--json" => parsed.json = true,
+                "--timeout" => {
+                    parsed.budget.time = Some(parse_seconds(&args.next().ok_or_else(missing)?)?)
+                }
+                "--max-steps" => {
+                    parsed.budget.steps = Some(args.next().ok_or_else(missing)?.parse()?)
+                }
+                "-h" | "--help" => {
+                    println!("{}", usage(&program));
+                    std::process::exit(0);
+                }
+                _ => bail!("Unknown option '{}'\n{}", arg, usage(&program)),
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Reads the whole input, from the input file if one was given or otherwise from stdin.
+    pub fn read_input(&self) -> Result<String, Report> {
+        match &self.input {
+            Some(path) => {
+                read_to_string(path).wrap_err_with(|| format!("Reading {}", path.display()))
+            }
+            None => Ok(io::read_to_string(stdin().lock())?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        ["target/release/aoc-2024-day14"]
+            .iter()
+            .chain(args)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn parse_args() {
+        let parsed = Args::parse(args(&[
+            "--input",
+            "example.txt",
+            "--part",
+            "1",
+            "--param",
+            "width=11",
+            "--param",
+            "height = 7",
+            "--json",
+            "-v",
+            "--timeout",
+            "1.5",
+            "--max-steps",
+            "1000",
+        ]))
+        .unwrap();
+        assert_eq!(parsed.input, Some(PathBuf::from("example.txt")));
+        assert_eq!(parsed.parts, Parts::Part1);
+        assert_eq!(
+            parsed.params.iter().collect::<Vec<_>>(),
+            [("height", "7"), ("width", "11")]
+        );
+        assert!(parsed.json);
+        assert_eq!(parsed.level, Level::Verbose);
+        assert_eq!(
+            parsed.budget,
+            Budget {
+                time: Some(std::time::Duration::from_millis(1500)),
+                steps: Some(1000),
+            }
+        );
+        assert_eq!(Args::parse(args(&[])).unwrap(), Args::default());
+    }
+
+    #[test]
+    fn parse_invalid_args() {
+        assert!(Args::parse(args(&["--part", "3"])).is_err());
+        assert!(Args::parse(args(&["--param", "width"])).is_err());
+        assert!(Args::parse(args(&["--input"])).is_err());
+        assert!(Args::parse(args(&["--timeout", "never"])).is_err());
+        assert!(Args::parse(args(&["--max-steps", "-1"])).is_err());
+        assert!(Args::parse(args(&["2024"])).is_err());
+    }
+
+    #[test]
+    fn usage_names_binary() {
+        let error = Args::parse(args(&["--input"])).unwrap_err().to_string();
+        assert!(error.starts_with("Usage: aoc-2024-day14 [--input <file>]"));
+        assert!(usage("aoc-2024-day14").contains("\n                      [-v | --trace]"));
+    }
+
+    #[test]
+    fn get_params() {
+        let mut params = Params::default();
+        params.insert("width", "11");
+        params.insert("name", "x");
+        assert_eq!(params.get("width", 101).unwrap(), 11);
+        assert_eq!(params.get("height", 103).unwrap(), 103);
+        assert!(params.get("name", 0).is_err());
+        assert!(params.check(&["width", "name"]).is_ok());
+        assert!(params.check(&["width"]).is_err());
+        assert!(params.check(&[]).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use eyre::{Report, eyre};
+use std::{
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    time::{Duration, Instant},
+};
+
+/// How many steps to take between checks of the clock, as it is much slower than counting.
+const STEPS_PER_CLOCK_CHECK: u64 = 1024;
+
+/// Limits on how long a solution may run, where `None` means no limit.
+///
+/// Limits are cooperative: they are only enforced when a solution calls [`progress`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Budget {
+    pub time: Option<Duration>,
+    pub steps: Option<u64>,
+}
+
+/// Parses a time limit given in seconds, such as `2` or `0.5`.
+pub fn parse_seconds(s: &str) -> Result<Duration, Report> {
+    s.parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| eyre!("Invalid time limit '{}', expected seconds", s))
+}
+
+/// The error returned by [`progress`] once the budget has run out.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Timeout {
+    pub steps: u64,
+    pub elapsed: Duration,
+}
+
+impl Display for Timeout {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Timed out after {:.1?} and {} steps",
+            self.elapsed, self.steps
+        )
+    }
+}
+
+impl Error for Timeout {}
+
+#[derive(Clone, Copy, Debug)]
+struct State {
+    start: Instant,
+    deadline: Option<Instant>,
+    max_steps: Option<u64>,
+    steps: u64,
+}
+
+thread_local! {
+    static STATE: Cell<Option<State>> = const { Cell::new(None) };
+}
+
+/// Restores the previous budget when dropped, even if the solution panics.
+struct Restore(Option<State>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        STATE.set(self.0);
+    }
+}
+
+/// Runs `f` under the given budget, which calls to [`progress`] on this thread count against.
+pub fn with_budget<T>(budget: Budget, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let _restore = Restore(STATE.replace(Some(State {
+        start,
+        deadline: budget.time.map(|time| start + time),
+        max_steps: budget.steps,
+        steps: 0,
+    })));
+    f()
+}
+
+/// Records a step of a long-running loop, and returns a `Timeout` error if the current budget has
+/// run out. Outside [`with_budget`] this always succeeds.
+pub fn progress() -> Result<(), Timeout> {
+    let Some(mut state) = STATE.get() else {
+        return Ok(());
+    };
+    state.steps += 1;
+    STATE.set(Some(state));
+    let out_of_steps = state.max_steps.is_some_and(|max| state.steps > max);
+    let out_of_time = state.steps.is_multiple_of(STEPS_PER_CLOCK_CHECK)
+        && state
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline);
+    if out_of_steps || out_of_time {
+        Err(Timeout {
+            steps: state.steps,
+            elapsed: state.start.elapsed(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_forever() -> Result<(), Timeout> {
+        loop {
+            progress()?;
+        }
+    }
+
+    #[test]
+    fn unlimited() {
+        for _ in 0..10_000 {
+            progress().unwrap();
+        }
+        with_budget(Budget::default(), || {
+            for _ in 0..10_000 {
+                progress().unwrap();
+            }
+        });
+    }
+
+    #[test]
+    fn step_limit() {
+        let budget = Budget {
+            time: None,
+            steps: Some(100),
+        };
+        let timeout = with_budget(budget, count_forever).unwrap_err();
+        assert_eq!(timeout.steps, 101);
+        // The budget only applies within `with_budget`.
+        progress().unwrap();
+    }
+
+    #[test]
+    fn parse_time_limits() {
+        assert_eq!(parse_seconds("2").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_seconds("0.5").unwrap(), Duration::from_millis(500));
+        assert!(parse_seconds("-1").is_err());
+        assert!(parse_seconds("soon").is_err());
+    }
+
+    #[test]
+    fn time_limit() {
+        let budget = Budget {
+            time: Some(Duration::from_millis(10)),
+            steps: None,
+        };
+        let timeout = with_budget(budget, count_forever).unwrap_err();
+        assert!(timeout.elapsed >= Duration::from_millis(10));
+    }
+}
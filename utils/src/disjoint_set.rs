@@ -0,0 +1,139 @@
+/// A partition of the elements `0..len` into disjoint sets which can be merged, also known as
+/// union-find.
+///
+/// Uses path compression and union by size, so operations take amortised near-constant time.
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    /// The parent of each element, or the element itself if it represents its set.
+    parents: Vec<usize>,
+    /// The size of each set, only meaningful for the elements which represent them.
+    sizes: Vec<usize>,
+    /// The number of disjoint sets.
+    count: usize,
+}
+
+impl DisjointSet {
+    /// Creates a partition of `0..len` where every element is in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            count: len,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn component_count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the element which represents the set containing the given element.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // Point everything on the way straight at the root.
+        let mut element = element;
+        while self.parents[element] != root {
+            element = std::mem::replace(&mut self.parents[element], root);
+        }
+        root
+    }
+
+    /// Merges the sets containing the given elements, and returns whether they were separate.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (small, large) = if self.sizes[a] < self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.count -= 1;
+        true
+    }
+
+    /// Returns whether the given elements are in the same set.
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing the given element.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Returns the elements of each set, in order of their smallest elements.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> {
+        let mut components = Vec::<Vec<usize>>::new();
+        // The index in `components` of each root seen so far.
+        let mut indices = vec![None; self.len()];
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let index = *indices[root].get_or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[index].push(element);
+        }
+        components.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!(set.component_count(), 6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+        assert_eq!(set.component_count(), 3);
+        assert!(set.same_set(0, 3));
+        assert!(!set.same_set(0, 4));
+        assert_eq!(set.set_size(2), 4);
+        assert_eq!(set.set_size(5), 1);
+        assert_eq!(
+            set.components().collect::<Vec<_>>(),
+            [vec![0, 1, 2, 3], vec![4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn long_chain() {
+        let mut set = DisjointSet::new(100_000);
+        for i in 1..set.len() {
+            set.union(i - 1, i);
+        }
+        assert_eq!(set.component_count(), 1);
+        assert_eq!(set.set_size(0), 100_000);
+        assert!(set.same_set(0, 99_999));
+    }
+
+    #[test]
+    fn empty() {
+        let mut set = DisjointSet::new(0);
+        assert!(set.is_empty());
+        assert_eq!(set.component_count(), 0);
+        assert_eq!(set.components().count(), 0);
+    }
+}
@@ -0,0 +1,444 @@
+//! Directed graphs with string-labelled nodes, such as device connections or dependencies.
+//!
+//! Labels are interned to `usize` ids in the order they are first seen, so the algorithms work on
+//! plain indices. They are all iterative, so long graphs can't overflow the stack.
+
+use eyre::{Report, bail, eyre};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Write,
+};
+
+/// A directed graph whose nodes are identified by string labels.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Graph {
+    labels: Vec<String>,
+    ids: HashMap<String, usize>,
+    /// The successors of each node.
+    edges: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph from a list of nodes and their successors.
+    pub fn from_adjacency<S: AsRef<str>>(
+        adjacency: impl IntoIterator<Item = (S, impl IntoIterator<Item = S>)>,
+    ) -> Self {
+        let mut graph = Self::new();
+        for (from, successors) in adjacency {
+            let from = graph.node(from.as_ref());
+            for to in successors {
+                let to = graph.node(to.as_ref());
+                graph.add_edge(from, to);
+            }
+        }
+        graph
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Returns the id of the node with the given label, adding it if it is new.
+    pub fn node(&mut self, label: &str) -> usize {
+        if let Some(&id) = self.ids.get(label) {
+            return id;
+        }
+        let id = self.labels.len();
+        self.labels.push(label.to_owned());
+        self.ids.insert(label.to_owned(), id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    /// Returns the id of the node with the given label, if there is one.
+    pub fn id(&self, label: &str) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, id: usize) -> &str {
+        &self.labels[id]
+    }
+
+    /// Adds an edge between the given nodes, unless it is already present.
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        if !self.edges[from].contains(&to) {
+            self.edges[from].push(to);
+        }
+    }
+
+    /// Returns the successors of the given node, in the order their edges were added.
+    pub fn successors(&self, id: usize) -> &[usize] {
+        &self.edges[id]
+    }
+
+    /// Returns the same graph with every edge reversed.
+    pub fn reversed(&self) -> Self {
+        let mut edges = vec![Vec::new(); self.len()];
+        for (from, successors) in self.edges.iter().enumerate() {
+            for &to in successors {
+                edges[to].push(from);
+            }
+        }
+        Self {
+            labels: self.labels.clone(),
+            ids: self.ids.clone(),
+            edges,
+        }
+    }
+
+    /// Returns whether each node can be reached from the given node, including itself.
+    pub fn reachable_from(&self, from: usize) -> Vec<bool> {
+        let mut reachable = vec![false; self.len()];
+        reachable[from] = true;
+        let mut stack = vec![from];
+        while let Some(node) = stack.pop() {
+            for &next in &self.edges[node] {
+                if !reachable[next] {
+                    reachable[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Returns the nodes in an order where every edge goes forwards, or an error naming a cycle.
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Report> {
+        self.topological_sort_within(&vec![true; self.len()])
+    }
+
+    /// Returns the nodes marked in `include` in an order where every edge between them goes
+    /// forwards, or an error naming a cycle between them.
+    fn topological_sort_within(&self, include: &[bool]) -> Result<Vec<usize>, Report> {
+        let mut in_degrees = vec![0; self.len()];
+        for (from, successors) in self.edges.iter().enumerate() {
+            if include[from] {
+                for &to in successors {
+                    in_degrees[to] += 1;
+                }
+            }
+        }
+        let mut ready = (0..self.len())
+            .filter(|&node| include[node] && in_degrees[node] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::new();
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+            for &next in &self.edges[node] {
+                in_degrees[next] -= 1;
+                if include[next] && in_degrees[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+        if order.len() < include.iter().filter(|&&included| included).count() {
+            let cycle = self.find_cycle_within(include).unwrap_or_default();
+            bail!("Cycle in graph: {}", self.format_path(&cycle));
+        }
+        Ok(order)
+    }
+
+    /// Returns the nodes of a cycle, with the first repeated at the end, if there are any.
+    pub fn find_cycle(&self) -> Option<Vec<usize>> {
+        self.find_cycle_within(&vec![true; self.len()])
+    }
+
+    fn find_cycle_within(&self, include: &[bool]) -> Option<Vec<usize>> {
+        #[derive(Clone, Copy, Eq, PartialEq)]
+        enum State {
+            Unvisited,
+            OnPath,
+            Done,
+        }
+
+        let mut states = vec![State::Unvisited; self.len()];
+        for root in (0..self.len()).filter(|&node| include[node]) {
+            if states[root] != State::Unvisited {
+                continue;
+            }
+            // The current path, with the index of the next edge to follow from each node.
+            let mut path = vec![(root, 0)];
+            states[root] = State::OnPath;
+            while let Some((node, edge)) = path.last_mut() {
+                let node = *node;
+                let Some(&next) = self.edges[node].get(*edge) else {
+                    states[node] = State::Done;
+                    path.pop();
+                    continue;
+                };
+                *edge += 1;
+                if !include[next] {
+                    continue;
+                }
+                match states[next] {
+                    State::Unvisited => {
+                        states[next] = State::OnPath;
+                        path.push((next, 0));
+                    }
+                    State::OnPath => {
+                        let start = path.iter().position(|&(node, _)| node == next)?;
+                        let mut cycle = path[start..]
+                            .iter()
+                            .map(|&(node, _)| node)
+                            .collect::<Vec<_>>();
+                        cycle.push(next);
+                        return Some(cycle);
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the strongly connected components, in reverse topological order, so there are no
+    /// edges from a component to any later one.
+    ///
+    /// Uses Tarjan's algorithm.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let mut next_index = 0;
+        let mut indices = vec![None; self.len()];
+        // The smallest index reachable from each node through its descendants in the search.
+        let mut low_links = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+
+        for root in 0..self.len() {
+            if indices[root].is_some() {
+                continue;
+            }
+            let mut path = vec![(root, 0)];
+            indices[root] = Some(next_index);
+            low_links[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            while let Some((node, edge)) = path.last_mut() {
+                let node = *node;
+                if let Some(&next) = self.edges[node].get(*edge) {
+                    *edge += 1;
+                    if let Some(index) = indices[next] {
+                        if on_stack[next] {
+                            low_links[node] = low_links[node].min(index);
+                        }
+                    } else {
+                        indices[next] = Some(next_index);
+                        low_links[next] = next_index;
+                        next_index += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        path.push((next, 0));
+                    }
+                    continue;
+                }
+                path.pop();
+                if let Some(&(parent, _)) = path.last() {
+                    low_links[parent] = low_links[parent].min(low_links[node]);
+                }
+                if Some(low_links[node]) == indices[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// Returns the number of distinct paths from `from` to `to`, or an error if a cycle on the way
+    /// allows infinitely many or the count overflows.
+    pub fn count_paths(&self, from: usize, to: usize) -> Result<u64, Report> {
+        // Only nodes on some path from `from` to `to` matter, so cycles elsewhere are fine.
+        let reachable = self.reachable_from(from);
+        let include = self
+            .reversed()
+            .reachable_from(to)
+            .into_iter()
+            .zip(reachable)
+            .map(|(reaches_to, reachable)| reaches_to && reachable)
+            .collect::<Vec<_>>();
+        let mut counts = vec![0u64; self.len()];
+        counts[from] = 1;
+        for node in self.topological_sort_within(&include)? {
+            for &next in &self.edges[node] {
+                if include[next] {
+                    counts[next] = counts[next].checked_add(counts[node]).ok_or_else(|| {
+                        eyre!(
+                            "Too many paths from {} to {}",
+                            self.label(from),
+                            self.label(to)
+                        )
+                    })?;
+                }
+            }
+        }
+        Ok(counts[to])
+    }
+
+    /// Returns the graph in the DOT language, for rendering with Graphviz.
+    pub fn to_dot(&self) -> String {
+        let mut dot = "digraph {\n".to_string();
+        for (from, successors) in self.edges.iter().enumerate() {
+            let from_label = dot_id(&self.labels[from]);
+            if successors.is_empty() {
+                writeln!(dot, "    {from_label};").unwrap();
+            }
+            for &to in successors {
+                writeln!(dot, "    {from_label} -> {};", dot_id(&self.labels[to])).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Formats the given nodes as their labels separated by arrows.
+    pub fn format_path(&self, path: &[usize]) -> String {
+        path.iter()
+            .map(|&node| self.label(node))
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+}
+
+/// Quotes the given label as a DOT identifier.
+fn dot_id(label: &str) -> String {
+    format!("\"{}\"", label.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(adjacency: &[(&str, &[&str])]) -> Graph {
+        Graph::from_adjacency(
+            adjacency
+                .iter()
+                .map(|(from, successors)| (*from, successors.iter().copied())),
+        )
+    }
+
+    fn labels(graph: &Graph, nodes: &[usize]) -> Vec<String> {
+        nodes
+            .iter()
+            .map(|&node| graph.label(node).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn interning() {
+        let mut graph = graph(&[("a", &["b", "c"]), ("c", &["b", "b"])]);
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.id("d"), None);
+        assert_eq!(graph.node("b"), 1);
+        assert_eq!(graph.node("d"), 3);
+        assert_eq!(graph.successors(2), [1]);
+        assert_eq!(labels(&graph, graph.reversed().successors(1)), ["a", "c"]);
+    }
+
+    #[test]
+    fn topological_sort() {
+        let dag = graph(&[
+            ("shirt", &["tie", "belt"]),
+            ("tie", &["jacket"]),
+            ("belt", &["jacket"]),
+        ]);
+        assert_eq!(
+            labels(&dag, &dag.topological_sort().unwrap()),
+            ["shirt", "tie", "belt", "jacket"]
+        );
+        assert_eq!(dag.find_cycle(), None);
+
+        let cyclic = graph(&[("a", &["b"]), ("b", &["c"]), ("c", &["b"])]);
+        assert_eq!(
+            cyclic.topological_sort().unwrap_err().to_string(),
+            "Cycle in graph: b -> c -> b"
+        );
+        assert_eq!(
+            labels(&cyclic, &cyclic.find_cycle().unwrap()),
+            ["b", "c", "b"]
+        );
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+        let graph = graph(&[
+            ("a", &["b"]),
+            ("b", &["c", "d"]),
+            ("c", &["a"]),
+            ("d", &["e"]),
+            ("e", &["d", "f"]),
+        ]);
+        let components = graph
+            .strongly_connected_components()
+            .iter()
+            .map(|component| {
+                let mut labels = labels(&graph, component);
+                labels.sort();
+                labels
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(components, [vec!["f"], vec!["d", "e"], vec!["a", "b", "c"]]);
+    }
+
+    #[test]
+    fn reachability() {
+        let graph = graph(&[("a", &["b"]), ("b", &["c"]), ("d", &["a"])]);
+        assert_eq!(graph.reachable_from(0), [true, true, true, false]);
+        assert_eq!(graph.reachable_from(3), [true; 4]);
+    }
+
+    #[test]
+    fn count_paths() {
+        let graph = graph(&[
+            ("a", &["b", "c"]),
+            ("b", &["d"]),
+            ("c", &["d", "x"]),
+            ("d", &["e"]),
+            ("x", &["y"]),
+            ("y", &["x"]),
+        ]);
+        let id = |label| graph.id(label).unwrap();
+        assert_eq!(graph.count_paths(id("a"), id("e")).unwrap(), 2);
+        assert_eq!(graph.count_paths(id("a"), id("a")).unwrap(), 1);
+        assert_eq!(graph.count_paths(id("e"), id("a")).unwrap(), 0);
+        assert_eq!(
+            graph.count_paths(id("a"), id("y")).unwrap_err().to_string(),
+            "Cycle in graph: x -> y -> x"
+        );
+    }
+
+    #[test]
+    fn long_chain() {
+        let labels = (0..100_000).map(|i| i.to_string()).collect::<Vec<_>>();
+        let graph = Graph::from_adjacency(labels.windows(2).map(|pair| (&pair[0], [&pair[1]])));
+        assert_eq!(graph.count_paths(0, 99_999).unwrap(), 1);
+        assert_eq!(graph.strongly_connected_components().len(), 100_000);
+        assert_eq!(graph.find_cycle(), None);
+    }
+
+    #[test]
+    fn dot() {
+        let graph = graph(&[("a", &["b", "c\"d"])]);
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    \"a\" -> \"b\";\n    \"a\" -> \"c\\\"d\";\n    \"b\";\n    \"c\\\"d\";\n}\n"
+        );
+    }
+}
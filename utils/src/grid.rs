@@ -102,6 +102,24 @@ impl<T: Clone> Grid<T> {
         })
     }
 
+    /// Returns a copy of the part of the grid with the given top-left corner and size.
+    ///
+    /// Panics if the area is not entirely within the grid.
+    pub fn subgrid(&self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        assert!(x + width <= self.width && y + height <= self.height);
+        let elements = self
+            .rows()
+            .skip(y)
+            .take(height)
+            .flat_map(|row| row[x..x + width].iter().cloned())
+            .collect();
+        Self {
+            width,
+            height,
+            elements,
+        }
+    }
+
     /// Returns a copy of the grid flipped vertically.
     #[allow(unused)]
     pub fn flip_vertical(&self) -> Self {
@@ -182,11 +200,12 @@ impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
     fn try_from(value: Vec<Vec<T>>) -> Result<Self, Self::Error> {
         let height = value.len();
         let width = value.first().map(Vec::len).unwrap_or_default();
-        for row in value.iter() {
+        for (i, row) in value.iter().enumerate() {
             if row.len() != width {
                 bail!(
-                    "First row was {} elements long but another row is {} elements",
+                    "First row was {} elements long but row {} is {} elements",
                     width,
+                    i + 1,
                     row.len()
                 );
             }
@@ -203,6 +222,8 @@ impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
 mod tests {
     use super::*;
     use crate::charvec;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
 
     #[test]
     fn size() {
@@ -246,6 +267,12 @@ mod tests {
         assert_eq!(grid.height(), 3);
         assert_eq!(grid.elements, Box::from([11, 12, 21, 22, 31, 32]));
 
+        assert_eq!(
+            Grid::try_from(vec![vec![1], vec![2], vec![3, 4]])
+                .unwrap_err()
+                .to_string(),
+            "First row was 1 elements long but row 3 is 2 elements"
+        );
         assert!(Grid::try_from(vec![vec![], vec![1]]).is_err());
     }
 
@@ -313,6 +340,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn subgrid() {
+        //  1  2  3
+        // 10 20 30
+        let grid = Grid::try_from(vec![vec![1, 2, 3], vec![10, 20, 30]]).unwrap();
+        assert_eq!(
+            grid.subgrid(1, 0, 2, 2),
+            Grid::try_from(vec![vec![2, 3], vec![20, 30]]).unwrap()
+        );
+        assert_eq!(
+            grid.subgrid(0, 1, 3, 1),
+            Grid::try_from(vec![vec![10, 20, 30]]).unwrap()
+        );
+        assert_eq!(grid.subgrid(1, 1, 0, 0), Grid::new(0, 0));
+    }
+
     #[test]
     fn rotate() {
         //  1  2  3
@@ -321,4 +364,43 @@ mod tests {
         let expected = Grid::try_from(vec![vec![10, 1], vec![20, 2], vec![30, 3]]).unwrap();
         assert_eq!(grid.rotate_clockwise(), expected);
     }
+
+    proptest! {
+        #[test]
+        fn four_rotations(grid: Grid<u8>) {
+            let rotated = grid.rotate_clockwise();
+            prop_assert_eq!(rotated.width(), grid.height());
+            prop_assert_eq!(rotated.height(), grid.width());
+            prop_assert_eq!(
+                rotated.rotate_clockwise().rotate_clockwise().rotate_clockwise(),
+                grid
+            );
+        }
+
+        #[test]
+        fn double_flips(grid: Grid<u8>) {
+            prop_assert_eq!(&grid.flip_horizonal().flip_horizonal(), &grid);
+            prop_assert_eq!(&grid.flip_vertical().flip_vertical(), &grid);
+            prop_assert_eq!(
+                grid.flip_horizonal().flip_vertical(),
+                grid.rotate_clockwise().rotate_clockwise()
+            );
+        }
+
+        #[test]
+        fn diagonals_cover_cells_twice(grid: Grid<()>) {
+            let positions = Grid::try_from(
+                (0..grid.height())
+                    .map(|y| (0..grid.width()).map(|x| (x, y)).collect())
+                    .collect::<Vec<Vec<_>>>(),
+            )
+            .unwrap();
+            let mut counts = HashMap::new();
+            for position in positions.diagonals().flatten() {
+                *counts.entry(position).or_insert(0) += 1;
+            }
+            prop_assert_eq!(counts.len(), grid.width() * grid.height());
+            prop_assert!(counts.values().all(|&count| count == 2));
+        }
+    }
 }
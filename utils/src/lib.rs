@@ -1,19 +1,36 @@
+#[cfg(feature = "count-allocations")]
+pub mod alloc;
+pub mod answers;
+pub mod args;
+pub mod budget;
+pub mod disjoint_set;
+pub mod graph;
 pub mod grid;
+pub mod log;
+pub mod memo;
+pub mod order_rules;
+pub mod parse;
+pub mod random;
+pub mod range_set;
+pub mod solution;
+#[cfg(any(test, feature = "proptest"))]
+pub mod strategies;
 
 use eyre::Report;
 use grid::Grid;
+use parse::parse_grid;
 use std::io::BufRead;
 
 pub fn charvec(s: &str) -> Vec<char> {
     s.chars().collect()
 }
 
+/// Parses the input as a grid of characters.
+///
+/// Returns an error with the location of the first row which is a different length from the
+/// first.
 pub fn parse_chargrid(input: impl BufRead) -> Result<Grid<char>, Report> {
-    input
-        .lines()
-        .map(|line| Ok(charvec(line?.trim_end_matches('\n'))))
-        .collect::<Result<Vec<_>, Report>>()?
-        .try_into()
+    parse_grid(input, Ok)
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -83,3 +100,50 @@ impl Direction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use strategies::MAX_SIZE;
+
+    /// A position within a grid, along with the grid's size.
+    fn position() -> impl Strategy<Value = ((usize, usize), usize, usize)> {
+        (1..=MAX_SIZE, 1..=MAX_SIZE)
+            .prop_flat_map(|(width, height)| ((0..width, 0..height), Just(width), Just(height)))
+    }
+
+    proptest! {
+        #[test]
+        fn four_turns(direction: Direction) {
+            prop_assert_eq!(
+                direction
+                    .rotate_clockwise()
+                    .rotate_clockwise()
+                    .rotate_clockwise()
+                    .rotate_clockwise(),
+                direction
+            );
+            prop_assert_eq!(direction.rotate_clockwise().rotate_anticlockwise(), direction);
+        }
+
+        #[test]
+        fn move_from_stays_in_bounds(direction: Direction, (start, width, height) in position()) {
+            match direction.move_from(start, width, height) {
+                Some(end) => {
+                    prop_assert!(end.0 < width && end.1 < height);
+                    prop_assert_eq!(end.0.abs_diff(start.0) + end.1.abs_diff(start.1), 1);
+                }
+                None => {
+                    let at_edge = match direction {
+                        Direction::Up => start.1 == 0,
+                        Direction::Down => start.1 == height - 1,
+                        Direction::Left => start.0 == 0,
+                        Direction::Right => start.0 == width - 1,
+                    };
+                    prop_assert!(at_edge);
+                }
+            }
+        }
+    }
+}
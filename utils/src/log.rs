@@ -0,0 +1,90 @@
+//! Diagnostic output for solutions, printed to stderr so that it doesn't mix with the answers.
+//!
+//! Nothing is printed unless the level has been raised, for example by the `-v` or `--trace`
+//! switches which every binary accepts.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+/// How much diagnostic output to print.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    /// Print nothing.
+    #[default]
+    Quiet = 0,
+    /// Print progress and interesting intermediate results.
+    Verbose = 1,
+    /// Print every step of a search.
+    Trace = 2,
+}
+
+impl Level {
+    /// Returns the level selected by a command-line switch, or `None` if it isn't one.
+    pub fn from_switch(arg: &str) -> Option<Self> {
+        match arg {
+            "-v" | "--verbose" => Some(Self::Verbose),
+            "--trace" => Some(Self::Trace),
+            _ => None,
+        }
+    }
+}
+
+/// Sets the level of diagnostic output to print.
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Returns whether messages at the given level should be printed.
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Prints a message to stderr if verbose output is enabled.
+///
+/// The arguments are only evaluated if the message will be printed.
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Verbose) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints a message to stderr if trace output is enabled.
+///
+/// The arguments are only evaluated if the message will be printed.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switches() {
+        assert_eq!(Level::from_switch("-v"), Some(Level::Verbose));
+        assert_eq!(Level::from_switch("--verbose"), Some(Level::Verbose));
+        assert_eq!(Level::from_switch("--trace"), Some(Level::Trace));
+        assert_eq!(Level::from_switch("--part"), None);
+    }
+
+    #[test]
+    fn levels() {
+        assert!(enabled(Level::Quiet));
+        set_level(Level::Trace);
+        assert!(enabled(Level::Verbose));
+        assert!(enabled(Level::Trace));
+        set_level(Level::Verbose);
+        assert!(!enabled(Level::Trace));
+        set_level(Level::Quiet);
+        assert!(!enabled(Level::Verbose));
+    }
+}
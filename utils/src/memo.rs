@@ -0,0 +1,276 @@
+//! Memoisation of recursive functions, with optional cycle detection.
+//!
+//! A recursive function takes a `&mut Memo` and passes it to a closure which computes its result,
+//! and which recurses by calling the function again with the memo it is given.
+
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt::{self, Display, Formatter},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+/// A map which can be used as the cache of a [`Memo`], looked up by a borrowed form `Q` of its
+/// keys.
+pub trait Cache<Q: ?Sized, K, V>: Default {
+    fn get(&self, key: &Q) -> Option<&V>;
+    fn insert(&mut self, key: K, value: V);
+    fn remove(&mut self, key: &Q);
+}
+
+impl<Q, K, V> Cache<Q, K, V> for HashMap<K, V>
+where
+    Q: Eq + Hash + ?Sized,
+    K: Borrow<Q> + Eq + Hash,
+{
+    fn get(&self, key: &Q) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+
+    fn remove(&mut self, key: &Q) {
+        HashMap::remove(self, key);
+    }
+}
+
+impl<Q, K, V> Cache<Q, K, V> for BTreeMap<K, V>
+where
+    Q: Ord + ?Sized,
+    K: Borrow<Q> + Ord,
+{
+    fn get(&self, key: &Q) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        BTreeMap::insert(self, key, value);
+    }
+
+    fn remove(&mut self, key: &Q) {
+        BTreeMap::remove(self, key);
+    }
+}
+
+/// An entry in the cache of a [`Memo`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Entry<V> {
+    /// The value is being computed further up the stack.
+    InProgress,
+    Done(V),
+}
+
+/// A memo backed by a `HashMap`.
+pub type HashMemo<K, V> = Memo<K, V, HashMap<K, Entry<V>>>;
+
+/// A memo backed by a `BTreeMap`, for keys which are `Ord` but not `Hash`.
+pub type BTreeMemo<K, V> = Memo<K, V, BTreeMap<K, Entry<V>>>;
+
+/// The error returned by [`Memo::try_get_or_compute`] when computing a value needs the same value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle;
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Memoised value depends on itself")
+    }
+}
+
+impl Error for Cycle {}
+
+/// How a [`Memo`] has been used.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /// The number of lookups which found a cached value.
+    pub hits: u64,
+    /// The number of values computed.
+    pub misses: u64,
+    /// The number of cycles detected.
+    pub cycles: u64,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} cycles",
+            self.hits, self.misses, self.cycles
+        )
+    }
+}
+
+/// A cache of the results of a function from keys `K` to values `V`, stored in a map `M`.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V, M> {
+    cache: M,
+    stats: Stats,
+    _types: PhantomData<(K, V)>,
+}
+
+impl<K, V, M: Default> Default for Memo<K, V, M> {
+    fn default() -> Self {
+        Self {
+            cache: M::default(),
+            stats: Stats::default(),
+            _types: PhantomData,
+        }
+    }
+}
+
+impl<K, V: Clone, M> Memo<K, V, M> {
+    pub fn new() -> Self
+    where
+        M: Default,
+    {
+        Self::default()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Returns the cached value for the given key, or computes it with `f` and caches it.
+    ///
+    /// `f` may call back into the memo for other keys. Cycles are not detected, so a key which
+    /// depends on itself recurses until the stack overflows.
+    pub fn get_or_compute<Q>(&mut self, key: &Q, f: impl FnOnce(&mut Self, &Q) -> V) -> V
+    where
+        Q: ToOwned<Owned = K> + ?Sized,
+        M: Cache<Q, K, Entry<V>>,
+    {
+        if let Some(Entry::Done(value)) = self.cache.get(key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = f(self, key);
+        self.cache
+            .insert(key.to_owned(), Entry::Done(value.clone()));
+        value
+    }
+
+    /// Returns the cached value for the given key, or computes it with `f` and caches it if it
+    /// succeeds.
+    ///
+    /// If `f` needs the value for the same key, directly or through other keys, the inner lookup
+    /// returns a [`Cycle`] error rather than recursing forever. Errors are not cached.
+    ///
+    /// If `f` handles a `Cycle` from an inner lookup rather than returning it, its value is still
+    /// cached, although it was computed without the keys that were in progress further up the
+    /// stack. Later lookups may then get a different value than a fresh computation would, one
+    /// which depends on the order the keys were first looked up in. This is only safe when such
+    /// values are the same whichever way they are reached, so propagate the error otherwise.
+    pub fn try_get_or_compute<Q, E: From<Cycle>>(
+        &mut self,
+        key: &Q,
+        f: impl FnOnce(&mut Self, &Q) -> Result<V, E>,
+    ) -> Result<V, E>
+    where
+        Q: ToOwned<Owned = K> + ?Sized,
+        M: Cache<Q, K, Entry<V>>,
+    {
+        match self.cache.get(key) {
+            Some(Entry::Done(value)) => {
+                self.stats.hits += 1;
+                return Ok(value.clone());
+            }
+            Some(Entry::InProgress) => {
+                self.stats.cycles += 1;
+                return Err(Cycle.into());
+            }
+            None => {}
+        }
+        self.stats.misses += 1;
+        self.cache.insert(key.to_owned(), Entry::InProgress);
+        let result = f(self, key);
+        match &result {
+            Ok(value) => self
+                .cache
+                .insert(key.to_owned(), Entry::Done(value.clone())),
+            Err(_) => self.cache.remove(key),
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &mut HashMemo<u64, u64>, n: u64) -> u64 {
+        memo.get_or_compute(&n, |memo, &n| {
+            if n < 2 {
+                n
+            } else {
+                fibonacci(memo, n - 1) + fibonacci(memo, n - 2)
+            }
+        })
+    }
+
+    #[test]
+    fn recursion() {
+        let mut memo = HashMemo::new();
+        assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 88,
+                misses: 91,
+                cycles: 0
+            }
+        );
+    }
+
+    /// Returns the number of ways to split `s` into the given words.
+    fn splits(memo: &mut BTreeMemo<String, usize>, words: &[&str], s: &str) -> usize {
+        memo.get_or_compute(s, |memo, s| {
+            if s.is_empty() {
+                return 1;
+            }
+            words
+                .iter()
+                .filter_map(|word| s.strip_prefix(word))
+                .map(|rest| splits(memo, words, rest))
+                .sum()
+        })
+    }
+
+    #[test]
+    fn borrowed_keys() {
+        let mut memo = BTreeMemo::new();
+        assert_eq!(splits(&mut memo, &["a", "ab", "b"], "abab"), 4);
+        assert_eq!(memo.stats().misses, 5);
+        splits(&mut memo, &["a", "ab", "b"], "abab");
+        assert_eq!(memo.stats().hits, 3);
+    }
+
+    /// Returns the length of the longest path from `node` in a graph given as lists of edges.
+    fn longest_path(
+        memo: &mut HashMemo<usize, usize>,
+        edges: &[Vec<usize>],
+        node: usize,
+    ) -> Result<usize, Cycle> {
+        memo.try_get_or_compute(&node, |memo, &node| {
+            edges[node]
+                .iter()
+                .map(|&next| Ok(longest_path(memo, edges, next)? + 1))
+                .try_fold(0, |longest, length| Ok(length?.max(longest)))
+        })
+    }
+
+    #[test]
+    fn cycles() {
+        let edges = [vec![1, 2], vec![2], vec![], vec![4], vec![3, 2]];
+        let mut memo = HashMemo::new();
+        assert_eq!(longest_path(&mut memo, &edges, 0), Ok(2));
+        assert_eq!(longest_path(&mut memo, &edges, 3), Err(Cycle));
+        assert_eq!(memo.stats().cycles, 1);
+        // The failed lookups aren't cached, so they fail the same way again.
+        assert_eq!(longest_path(&mut memo, &edges, 4), Err(Cycle));
+        assert_eq!(memo.stats().cycles, 2);
+    }
+}
@@ -0,0 +1,302 @@
+//! Rules that some items must come before others, such as page ordering rules.
+//!
+//! The rules need not form a consistent order overall, as long as they do for each set of items
+//! that is checked or sorted.
+
+use eyre::{Report, bail};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+    hash::Hash,
+};
+
+/// A set of rules, each saying that one item must come before another.
+///
+/// Two sets are equal if they have the same rules, whatever order the rules were added in.
+#[derive(Clone, Debug)]
+pub struct OrderRules<T> {
+    /// Every item mentioned by a rule, in the order they were first seen.
+    items: Vec<T>,
+    /// The items which must come after each item.
+    after: HashMap<T, HashSet<T>>,
+}
+
+impl<T> Default for OrderRules<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            after: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash> PartialEq for OrderRules<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.after == other.after
+    }
+}
+
+impl<T: Eq + Hash> Eq for OrderRules<T> {}
+
+/// A rule broken by a sequence of items.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Violation<T> {
+    pub before: T,
+    pub after: T,
+    /// The index of `before` in the sequence, which is after `after_index`.
+    pub before_index: usize,
+    pub after_index: usize,
+}
+
+impl<T: Display> Display for Violation<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} must come before {}, but is at index {} after it at {}",
+            self.before, self.after, self.before_index, self.after_index
+        )
+    }
+}
+
+impl<T: Debug + Display> Error for Violation<T> {}
+
+impl<T: Copy + Display + Eq + Hash> OrderRules<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule that `before` must come before `after`.
+    pub fn add(&mut self, before: T, after: T) {
+        for item in [before, after] {
+            if !self.after.contains_key(&item) {
+                self.items.push(item);
+                self.after.insert(item, HashSet::new());
+            }
+        }
+        self.after.get_mut(&before).unwrap().insert(after);
+    }
+
+    /// Returns whether there is a rule that `before` must come before `after`.
+    pub fn must_precede(&self, before: T, after: T) -> bool {
+        self.after
+            .get(&before)
+            .is_some_and(|afters| afters.contains(&after))
+    }
+
+    /// Checks that the given sequence of items breaks none of the rules, or returns the first rule
+    /// it breaks.
+    ///
+    /// The first broken rule is the one whose `before` item is earliest in the sequence, and then
+    /// whose `after` item is earliest. An item which appears more than once breaks a rule if any
+    /// of its copies does.
+    pub fn check(&self, sequence: &[T]) -> Result<(), Violation<T>> {
+        let positions = positions(sequence);
+        for (before_index, before) in sequence.iter().enumerate() {
+            let Some(afters) = self.after.get(before) else {
+                continue;
+            };
+            if let Some((after_index, after)) = afters
+                .iter()
+                .filter_map(|after| Some((positions.get(after)?[0], *after)))
+                .filter(|(after_index, _)| *after_index < before_index)
+                .min_by_key(|(after_index, _)| *after_index)
+            {
+                return Err(Violation {
+                    before: *before,
+                    after,
+                    before_index,
+                    after_index,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the given items in an order which breaks none of the rules between them, or an error
+    /// naming a cycle of rules between them which no order can satisfy.
+    ///
+    /// An item which appears more than once is kept as many times, with every copy placed after
+    /// the items which must precede it.
+    ///
+    /// This is Kahn's algorithm, always taking the earliest of the given items which no remaining
+    /// item must precede. It is not stable: an item with no rules may move ahead of earlier items
+    /// which are waiting for theirs.
+    pub fn sort(&self, items: &[T]) -> Result<Vec<T>, Report> {
+        let positions = positions(items);
+        let mut successors = vec![Vec::new(); items.len()];
+        let mut predecessors = vec![Vec::new(); items.len()];
+        for (index, item) in items.iter().enumerate() {
+            for after in self.after.get(item).into_iter().flatten() {
+                for &after_index in positions.get(after).into_iter().flatten() {
+                    successors[index].push(after_index);
+                    predecessors[after_index].push(index);
+                }
+            }
+        }
+
+        let mut in_degrees = predecessors.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut ready = (0..items.len())
+            .filter(|&index| in_degrees[index] == 0)
+            .collect::<BTreeSet<_>>();
+        let mut sorted = Vec::with_capacity(items.len());
+        while let Some(index) = ready.pop_first() {
+            sorted.push(items[index]);
+            for &next in &successors[index] {
+                in_degrees[next] -= 1;
+                if in_degrees[next] == 0 {
+                    ready.insert(next);
+                }
+            }
+        }
+        if sorted.len() < items.len() {
+            let cycle = find_cycle(&predecessors, &in_degrees)
+                .into_iter()
+                .map(|index| items[index].to_string())
+                .collect::<Vec<_>>();
+            bail!("Cyclic rules: {}", cycle.join(" before "));
+        }
+        Ok(sorted)
+    }
+
+    /// Checks that there is some order of all the items mentioned by the rules which breaks none of
+    /// them, or returns an error naming a cycle of rules.
+    pub fn check_consistent(&self) -> Result<(), Report> {
+        self.sort(&self.items).map(|_| ())
+    }
+}
+
+impl<T: Copy + Display + Eq + Hash> FromIterator<(T, T)> for OrderRules<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(rules: I) -> Self {
+        let mut order_rules = Self::new();
+        for (before, after) in rules {
+            order_rules.add(before, after);
+        }
+        order_rules
+    }
+}
+
+/// Returns the indices at which each of the given items appears, in order.
+fn positions<T: Copy + Eq + Hash>(items: &[T]) -> HashMap<T, Vec<usize>> {
+    let mut positions = HashMap::<T, Vec<usize>>::new();
+    for (index, item) in items.iter().enumerate() {
+        positions.entry(*item).or_default().push(index);
+    }
+    positions
+}
+
+/// Returns a cycle of indices starting from the smallest, with it repeated at the end, among those
+/// with a non-zero in-degree left after a topological sort.
+///
+/// Each of those has a predecessor which is also left, so following predecessors must loop.
+fn find_cycle(predecessors: &[Vec<usize>], in_degrees: &[usize]) -> Vec<usize> {
+    let Some(start) = (0..in_degrees.len()).find(|&index| in_degrees[index] > 0) else {
+        return Vec::new();
+    };
+    let mut walk = vec![start];
+    let mut seen = HashMap::from([(start, 0)]);
+    let mut current = start;
+    while let Some(&previous) = predecessors[current]
+        .iter()
+        .find(|&&previous| in_degrees[previous] > 0)
+    {
+        if let Some(&position) = seen.get(&previous) {
+            let mut cycle = walk.split_off(position);
+            cycle.reverse();
+            // Start from the earliest item, so the same cycle is always reported the same way.
+            let earliest = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap_or(0);
+            cycle.rotate_left(earliest);
+            cycle.push(cycle[0]);
+            return cycle;
+        }
+        seen.insert(previous, walk.len());
+        walk.push(previous);
+        current = previous;
+    }
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(rules: &[(u32, u32)]) -> OrderRules<u32> {
+        rules.iter().copied().collect()
+    }
+
+    #[test]
+    fn check() {
+        let rules = rules(&[(1, 2), (2, 3), (1, 4)]);
+        assert!(rules.must_precede(1, 2));
+        assert!(!rules.must_precede(1, 3));
+        assert_eq!(rules.check(&[1, 2, 3, 4]), Ok(()));
+        assert_eq!(rules.check(&[4, 3, 5]), Ok(()));
+        assert_eq!(
+            rules.check(&[4, 3, 2, 1]),
+            Err(Violation {
+                before: 2,
+                after: 3,
+                before_index: 2,
+                after_index: 1
+            })
+        );
+        assert_eq!(
+            rules.check(&[3, 4, 1, 2]).unwrap_err().to_string(),
+            "1 must come before 4, but is at index 2 after it at 1"
+        );
+    }
+
+    #[test]
+    fn sort() {
+        let rules = rules(&[(1, 2), (2, 3), (1, 4)]);
+        assert_eq!(rules.sort(&[3, 2, 1]).unwrap(), [1, 2, 3]);
+        // 4 and 5 have no rules relating them to 2 or 3, so stay before them.
+        assert_eq!(rules.sort(&[4, 5, 3, 2]).unwrap(), [4, 5, 2, 3]);
+        // 4 must wait for 1, so 5 goes first.
+        assert_eq!(rules.sort(&[4, 5, 3, 2, 1]).unwrap(), [5, 1, 4, 2, 3]);
+        assert!(rules.sort(&[]).unwrap().is_empty());
+        assert!(rules.check_consistent().is_ok());
+    }
+
+    #[test]
+    fn repeated_items() {
+        let rules = rules(&[(1, 2), (2, 3)]);
+        assert!(rules.check(&[1, 1, 2, 3, 3]).is_ok());
+        assert_eq!(
+            rules.check(&[2, 1, 2]),
+            Err(Violation {
+                before: 1,
+                after: 2,
+                before_index: 1,
+                after_index: 0,
+            })
+        );
+        assert_eq!(
+            rules.check(&[1, 2, 1]).unwrap_err().to_string(),
+            "1 must come before 2, but is at index 2 after it at 1"
+        );
+        assert_eq!(rules.sort(&[3, 2, 1, 2, 3]).unwrap(), [1, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(rules(&[(1, 2), (3, 4)]), rules(&[(3, 4), (1, 2)]));
+        assert_eq!(rules(&[(1, 2), (1, 2)]), rules(&[(1, 2)]));
+        assert_ne!(rules(&[(1, 2)]), rules(&[(2, 1)]));
+    }
+
+    #[test]
+    fn cycles() {
+        let rules = rules(&[(1, 2), (2, 3), (3, 1), (4, 4), (5, 6)]);
+        assert_eq!(
+            rules.check_consistent().unwrap_err().to_string(),
+            "Cyclic rules: 1 before 2 before 3 before 1"
+        );
+        // The cycle doesn't matter for subsets which break it.
+        assert_eq!(rules.sort(&[3, 2, 6, 5]).unwrap(), [2, 3, 5, 6]);
+        assert_eq!(
+            rules.sort(&[5, 4]).unwrap_err().to_string(),
+            "Cyclic rules: 4 before 4"
+        );
+    }
+}
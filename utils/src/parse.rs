@@ -0,0 +1,413 @@
+use crate::grid::Grid;
+use eyre::{Report, WrapErr, eyre};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io::BufRead,
+    str::FromStr,
+};
+
+/// The maximum number of characters to include in a snippet either side of the error column.
+const SNIPPET_CONTEXT: usize = 30;
+
+/// The location in the input of a parse error, along with a snippet of the input around it.
+///
+/// This is usually attached as context to the underlying error with `wrap_err`, so that both the
+/// location and the cause are reported.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    line: usize,
+    column: Option<usize>,
+    snippet: String,
+    /// The offset in characters of the error column within `snippet`.
+    snippet_offset: usize,
+}
+
+impl ParseError {
+    /// Creates a new error for the given 1-based line number, where `text` is the contents of the
+    /// line.
+    pub fn at_line(line: usize, text: &str) -> Self {
+        let (snippet, _) = snippet(text, 0);
+        Self {
+            line,
+            column: None,
+            snippet,
+            snippet_offset: 0,
+        }
+    }
+
+    /// Creates a new error for the given 1-based line and column numbers, where `text` is the
+    /// contents of the line.
+    pub fn at(line: usize, column: usize, text: &str) -> Self {
+        let (snippet, snippet_offset) = snippet(text, column.saturating_sub(1));
+        Self {
+            line,
+            column: Some(column),
+            snippet,
+            snippet_offset,
+        }
+    }
+
+    /// Creates a new error for the element at the given position of a grid of characters, such as
+    /// one returned by `parse_chargrid`.
+    pub fn in_grid(grid: &Grid<char>, x: usize, y: usize) -> Self {
+        let row = grid
+            .rows()
+            .nth(y)
+            .map(|row| row.iter().collect::<String>())
+            .unwrap_or_default();
+        Self::at(y + 1, x + 1, &row)
+    }
+
+    /// Returns the 1-based line number of the error.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the 1-based column number of the error, if it is known.
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    /// Returns the part of the line around the error.
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Invalid input at line {}", self.line)?;
+        if let Some(column) = self.column {
+            write!(f, ", column {}", column)?;
+        }
+        write!(f, ":\n    {}", self.snippet)?;
+        if self.column.is_some() {
+            write!(f, "\n    {}^", " ".repeat(self.snippet_offset))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returns a snippet of the given line around the given 0-based column, along with the offset of
+/// that column within the snippet.
+fn snippet(text: &str, column: usize) -> (String, usize) {
+    let length = text.chars().count();
+    let start = column.saturating_sub(SNIPPET_CONTEXT).min(length);
+    let end = (start + 2 * SNIPPET_CONTEXT + 1).min(length);
+    let mut snippet = String::new();
+    let mut offset = column - start;
+    if start > 0 {
+        snippet.push_str("...");
+        offset += 3;
+    }
+    snippet.extend(text.chars().skip(start).take(end - start));
+    if end < length {
+        snippet.push_str("...");
+    }
+    (snippet, offset)
+}
+
+/// Reads all lines of the input, normalising line endings.
+///
+/// Both LF and CRLF line endings are accepted, as is a missing newline at the end of the final
+/// line. Blank lines at the end of the input are dropped.
+pub fn read_lines(input: impl BufRead) -> Result<Vec<String>, Report> {
+    let mut lines = input
+        .lines()
+        .map(|line| {
+            let mut line = line?;
+            if line.ends_with('\r') {
+                line.pop();
+            }
+            Ok(line)
+        })
+        .collect::<Result<Vec<_>, Report>>()?;
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    Ok(lines)
+}
+
+/// Parses each line of the input with the given function, adding the line number to any error.
+///
+/// Line endings are normalised as for `read_lines`.
+pub fn parse_lines<T>(
+    input: impl BufRead,
+    mut f: impl FnMut(&str) -> Result<T, Report>,
+) -> Result<Vec<T>, Report> {
+    read_lines(input)?
+        .iter()
+        .enumerate()
+        .map(|(i, line)| f(line).wrap_err_with(|| ParseError::at_line(i + 1, line)))
+        .collect()
+}
+
+/// Parses each character of the given line with the given function, adding the line and column
+/// number to any error.
+///
+/// `line_number` is the 1-based number of the line within the input, used only for errors.
+pub fn parse_chars<T>(
+    line: &str,
+    line_number: usize,
+    mut f: impl FnMut(char) -> Result<T, Report>,
+) -> Result<Vec<T>, Report> {
+    line.chars()
+        .enumerate()
+        .map(|(i, c)| f(c).wrap_err_with(|| ParseError::at(line_number, i + 1, line)))
+        .collect()
+}
+
+/// Parses the input as a grid, converting each character with the given function.
+///
+/// Line endings are normalised as for `read_lines`. Errors include the line and column of the
+/// offending character, or of the end of the first row which has a different length from the
+/// first.
+pub fn parse_grid<T>(
+    input: impl BufRead,
+    mut f: impl FnMut(char) -> Result<T, Report>,
+) -> Result<Grid<T>, Report> {
+    let mut rows = Vec::new();
+    for (i, line) in read_lines(input)?.iter().enumerate() {
+        let row = parse_chars(line, i + 1, &mut f)?;
+        if let Some(first) = rows.first().map(Vec::len)
+            && row.len() != first
+        {
+            return Err(eyre!(
+                "First row was {} characters long but this row is {} characters",
+                first,
+                row.len()
+            )
+            .wrap_err(ParseError::at(i + 1, first.min(row.len()) + 1, line)));
+        }
+        rows.push(row);
+    }
+    rows.try_into()
+}
+
+/// The order in which to read the columns of a block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ColumnOrder {
+    LeftToRight,
+    RightToLeft,
+}
+
+/// Parses the input as a sequence of blocks laid out side by side, separated by columns containing
+/// only spaces.
+///
+/// Line endings are normalised as for `read_lines`. Lines shorter than the longest line are padded
+/// with spaces, as trailing spaces are often lost when editing.
+pub fn parse_column_blocks(input: impl BufRead) -> Result<Vec<Grid<char>>, Report> {
+    let mut rows = read_lines(input)?
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect::<Vec<Vec<char>>>();
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    for row in &mut rows {
+        row.resize(width, ' ');
+    }
+    Ok(column_blocks(&rows.try_into()?))
+}
+
+/// Splits the given grid into blocks separated by columns containing only spaces.
+///
+/// The blocks are returned in order from left to right, and each is the full height of the grid.
+pub fn column_blocks(grid: &Grid<char>) -> Vec<Grid<char>> {
+    let mut blocks = Vec::new();
+    let mut block_start = None;
+    for (x, column) in grid.columns().enumerate() {
+        let is_separator = column.iter().all(|&c| c == ' ');
+        match (block_start, is_separator) {
+            (Some(start), true) => {
+                blocks.push(grid.subgrid(start, 0, x - start, grid.height()));
+                block_start = None;
+            }
+            (None, false) => {
+                block_start = Some(x);
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = block_start {
+        blocks.push(grid.subgrid(start, 0, grid.width() - start, grid.height()));
+    }
+    blocks
+}
+
+/// Reads each column of the given block from top to bottom as a number, ignoring spaces.
+///
+/// Columns containing only spaces are skipped.
+pub fn column_numbers<T: FromStr>(block: &Grid<char>, order: ColumnOrder) -> Result<Vec<T>, Report>
+where
+    T::Err: Error + Send + Sync + 'static,
+{
+    let mut columns = block.columns().collect::<Vec<_>>();
+    if order == ColumnOrder::RightToLeft {
+        columns.reverse();
+    }
+    columns
+        .into_iter()
+        .map(|column| column.into_iter().filter(|&c| c != ' ').collect::<String>())
+        .filter(|digits| !digits.is_empty())
+        .map(|digits| {
+            digits
+                .parse()
+                .wrap_err_with(|| format!("Parsing column '{digits}'"))
+        })
+        .collect()
+}
+
+/// Reads each row of the given block from left to right as a number, ignoring surrounding spaces.
+///
+/// Rows containing only spaces are skipped.
+pub fn row_numbers<T: FromStr>(block: &Grid<char>) -> Result<Vec<T>, Report>
+where
+    T::Err: Error + Send + Sync + 'static,
+{
+    block
+        .rows()
+        .map(|row| row.iter().collect::<String>())
+        .filter(|row| !row.trim().is_empty())
+        .map(|row| {
+            row.trim()
+                .parse()
+                .wrap_err_with(|| format!("Parsing row '{row}'"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_chargrid;
+    use eyre::OptionExt;
+
+    #[test]
+    fn display_line() {
+        assert_eq!(
+            ParseError::at_line(3, "abc: def").to_string(),
+            "Invalid input at line 3:\n    abc: def"
+        );
+    }
+
+    #[test]
+    fn display_column() {
+        assert_eq!(
+            ParseError::at(2, 3, "..x..").to_string(),
+            "Invalid input at line 2, column 3:\n    ..x..\n      ^"
+        );
+    }
+
+    #[test]
+    fn long_line_snippet() {
+        let line = format!("{}x{}", "0".repeat(100), "1".repeat(100));
+        let error = ParseError::at(1, 101, &line);
+        assert_eq!(
+            error.snippet(),
+            format!("...{}x{}...", "0".repeat(30), "1".repeat(30))
+        );
+        assert!(
+            error
+                .to_string()
+                .ends_with(&format!("\n    {}^", " ".repeat(33)))
+        );
+    }
+
+    #[test]
+    fn read_lines_normalised() {
+        assert_eq!(read_lines("".as_bytes()).unwrap(), Vec::<String>::new());
+        assert_eq!(read_lines("a\nb\n".as_bytes()).unwrap(), vec!["a", "b"]);
+        assert_eq!(read_lines("a\r\nb\r\n".as_bytes()).unwrap(), vec!["a", "b"]);
+        assert_eq!(read_lines("a\r\nb\r".as_bytes()).unwrap(), vec!["a", "b"]);
+        assert_eq!(read_lines("a\nb".as_bytes()).unwrap(), vec!["a", "b"]);
+        assert_eq!(
+            read_lines("a\nb\n\n \n".as_bytes()).unwrap(),
+            vec!["a", "b"]
+        );
+        assert_eq!(
+            read_lines("a\n\nb\n".as_bytes()).unwrap(),
+            vec!["a", "", "b"]
+        );
+    }
+
+    #[test]
+    fn chargrid_crlf() {
+        let expected = Grid::try_from(vec![vec!['a', 'b'], vec!['c', 'd']]).unwrap();
+        assert_eq!(parse_chargrid("ab\r\ncd\r\n".as_bytes()).unwrap(), expected);
+        assert_eq!(parse_chargrid("ab\r\ncd".as_bytes()).unwrap(), expected);
+        assert_eq!(parse_chargrid("ab\ncd\n\n".as_bytes()).unwrap(), expected);
+    }
+
+    #[test]
+    fn lines_error_location() {
+        let error =
+            parse_lines("1\n2\nx\n4".as_bytes(), |line| Ok(line.parse::<u32>()?)).unwrap_err();
+        let location = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(location.line(), 3);
+        assert_eq!(location.column(), None);
+        assert_eq!(location.snippet(), "x");
+    }
+
+    #[test]
+    fn grid_error_location() {
+        let error = parse_grid("123\n4x6\n".as_bytes(), |c| {
+            c.to_digit(10).ok_or_eyre("Not a digit")
+        })
+        .unwrap_err();
+        let location = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(location.line(), 2);
+        assert_eq!(location.column(), Some(2));
+        assert_eq!(location.snippet(), "4x6");
+    }
+
+    #[test]
+    fn chargrid_ragged_location() {
+        let error = parse_chargrid("abc\nabc\nab\n".as_bytes()).unwrap_err();
+        let location = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(location.line(), 3);
+        assert_eq!(location.column(), Some(3));
+    }
+
+    #[test]
+    fn split_column_blocks() {
+        let blocks = parse_column_blocks("12  3\n4   56\n".as_bytes()).unwrap();
+        assert_eq!(
+            blocks,
+            vec![
+                Grid::try_from(vec![vec!['1', '2'], vec!['4', ' ']]).unwrap(),
+                Grid::try_from(vec![vec!['3', ' '], vec!['5', '6']]).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_column_blocks_empty() {
+        assert_eq!(parse_column_blocks("".as_bytes()).unwrap(), vec![]);
+        assert_eq!(parse_column_blocks("   \n  \n".as_bytes()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn read_block_numbers() {
+        // 12
+        //  3
+        let block = Grid::try_from(vec![vec!['1', '2'], vec![' ', '3']]).unwrap();
+        assert_eq!(row_numbers::<u32>(&block).unwrap(), vec![12, 3]);
+        assert_eq!(
+            column_numbers::<u32>(&block, ColumnOrder::LeftToRight).unwrap(),
+            vec![1, 23]
+        );
+        assert_eq!(
+            column_numbers::<u32>(&block, ColumnOrder::RightToLeft).unwrap(),
+            vec![23, 1]
+        );
+    }
+
+    #[test]
+    fn read_block_numbers_invalid() {
+        let block = Grid::try_from(vec![vec!['1', 'x']]).unwrap();
+        assert!(row_numbers::<u32>(&block).is_err());
+        assert!(column_numbers::<u32>(&block, ColumnOrder::LeftToRight).is_err());
+    }
+}
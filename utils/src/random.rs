@@ -0,0 +1,107 @@
+//! A small seeded random number generator, for generating synthetic puzzle inputs.
+//!
+//! This is SplitMix64 rather than anything from an external crate so that a given seed always
+//! produces the same input.
+
+use std::ops::Range;
+
+/// A seeded pseudo-random number generator. Not suitable for cryptography.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in the given range, which must not be empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "Empty range {:?}", range);
+        let width = range.end - range.start;
+        // Reject values from the incomplete last interval to avoid bias.
+        let limit = u64::MAX - u64::MAX % width;
+        loop {
+            let value = self.next_u64();
+            if value < limit {
+                return range.start + value % width;
+            }
+        }
+    }
+
+    /// Returns a number in the given range, which must not be empty.
+    pub fn range_i64(&mut self, range: Range<i64>) -> i64 {
+        let width = range.end.abs_diff(range.start);
+        range.start.wrapping_add_unsigned(self.range(0..width))
+    }
+
+    /// Returns an index into a slice of the given length, which must not be zero.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..len as u64) as usize
+    }
+
+    /// Returns true with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Returns a random element of the given slice, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffles the given slice in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+        let a = (0..10).map(|_| a.next_u64()).collect::<Vec<_>>();
+        assert_eq!(a, (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(a, (0..10).map(|_| c.next_u64()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let value = rng.range(10..16);
+            assert!((10..16).contains(&value));
+            seen[value as usize - 10] = true;
+            assert!((-3..2).contains(&rng.range_i64(-3..2)));
+        }
+        assert!(seen.iter().all(|&seen| seen));
+        assert_eq!(rng.range_i64(i64::MIN..i64::MIN + 1), i64::MIN);
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = Rng::new(7);
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,298 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::RangeInclusive,
+};
+
+/// An integer type which can be stored in a [`RangeSet`].
+pub trait Integer: Copy + Debug + Ord {
+    /// Returns the next integer, or `None` if this is the maximum.
+    fn successor(self) -> Option<Self>;
+
+    /// Returns the previous integer, or `None` if this is the minimum.
+    fn predecessor(self) -> Option<Self>;
+
+    /// Returns the number of integers in `start..=end`, saturating at `u128::MAX`.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end.abs_diff(start) as u128).saturating_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// A set of integers, stored as a sorted list of disjoint inclusive ranges.
+///
+/// Overlapping or adjacent ranges are merged as they are inserted, so the ranges are always as
+/// few as possible.
+#[derive(Clone, Eq, PartialEq)]
+pub struct RangeSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the set contains no integers.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the number of disjoint ranges in the set.
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns the number of integers in the set, saturating at `u128::MAX`.
+    pub fn total_len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|range| T::count(*range.start(), *range.end()))
+            .fold(0, u128::saturating_add)
+    }
+
+    /// Returns the disjoint ranges in order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    /// Returns whether the set contains the given value, by binary search.
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| *range.end() < value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| *range.start() <= value)
+    }
+
+    /// Adds the given range to the set, merging it with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // The ranges from `first` up to `last` overlap or touch the new one.
+        let first = self
+            .ranges
+            .partition_point(|range| range.end().successor().is_some_and(|after| after < start));
+        let last = self.ranges.partition_point(|range| {
+            range
+                .start()
+                .predecessor()
+                .is_none_or(|before| before <= end)
+        });
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    /// Returns the integers in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    /// Returns the integers in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Returns the integers in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        for range in &self.ranges {
+            // The start of the part of `range` which hasn't been covered yet, if any.
+            let mut rest = Some(*range.start());
+            let first = other
+                .ranges
+                .partition_point(|removed| removed.end() < range.start());
+            for removed in &other.ranges[first..] {
+                let Some(start) = rest else {
+                    break;
+                };
+                if removed.start() > range.end() {
+                    break;
+                }
+                if let Some(before) = removed.start().predecessor()
+                    && start <= before
+                {
+                    ranges.push(start..=before);
+                }
+                rest = removed.end().successor().map(|after| after.max(start));
+            }
+            if let Some(start) = rest
+                && start <= *range.end()
+            {
+                ranges.push(start..=*range.end());
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+impl<T: Debug> Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(&self.ranges).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+    use std::collections::BTreeSet;
+
+    fn ranges(set: &RangeSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        let mut set = RangeSet::new();
+        set.insert(10..=14);
+        set.insert(3..=5);
+        set.insert(16..=20);
+        assert_eq!(ranges(&set), [3..=5, 10..=14, 16..=20]);
+        set.insert(12..=18);
+        assert_eq!(ranges(&set), [3..=5, 10..=20]);
+        set.insert(6..=9);
+        assert_eq!(ranges(&set), [3..=20]);
+        #[allow(clippy::reversed_empty_ranges)]
+        set.insert(30..=29);
+        assert_eq!(ranges(&set), [3..=20]);
+        assert_eq!(set.total_len(), 18);
+    }
+
+    #[test]
+    fn contains() {
+        let set = [3..=5, 10..=14].into_iter().collect::<RangeSet<i32>>();
+        let contained = (0..16).filter(|&i| set.contains(i)).collect::<Vec<_>>();
+        assert_eq!(contained, [3, 4, 5, 10, 11, 12, 13, 14]);
+    }
+
+    #[test]
+    fn extremes() {
+        let mut set = RangeSet::new();
+        set.insert(u8::MAX..=u8::MAX);
+        set.insert(0..=0);
+        set.insert(1..=254);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0..=255]);
+        assert_eq!(set.total_len(), 256);
+        let without_ends = [1..=254].into_iter().collect::<RangeSet<u8>>();
+        assert_eq!(
+            set.difference(&without_ends).iter().collect::<Vec<_>>(),
+            [0..=0, 255..=255]
+        );
+        assert!(set.difference(&set).is_empty());
+        let everything = [u128::MIN..=u128::MAX].into_iter().collect::<RangeSet<_>>();
+        assert_eq!(everything.total_len(), u128::MAX);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = [1..=5, 10..=20].into_iter().collect::<RangeSet<i32>>();
+        let b = [4..=12, 18..=25].into_iter().collect::<RangeSet<i32>>();
+        assert_eq!(ranges(&a.union(&b)), [1..=25]);
+        assert_eq!(ranges(&a.intersection(&b)), [4..=5, 10..=12, 18..=20]);
+        assert_eq!(ranges(&a.difference(&b)), [1..=3, 13..=17]);
+        assert_eq!(ranges(&b.difference(&a)), [6..=9, 21..=25]);
+    }
+
+    fn small_ranges() -> impl Strategy<Value = Vec<RangeInclusive<u8>>> {
+        vec((0..=255u8, 0..=20u8), 0..8).prop_map(|ranges| {
+            ranges
+                .into_iter()
+                .map(|(start, length)| start..=start.saturating_add(length))
+                .collect()
+        })
+    }
+
+    fn model(ranges: &[RangeInclusive<u8>]) -> BTreeSet<u8> {
+        ranges.iter().cloned().flatten().collect()
+    }
+
+    fn values(set: &RangeSet<u8>) -> BTreeSet<u8> {
+        set.iter().flatten().collect()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_model(a in small_ranges(), b in small_ranges()) {
+            let (model_a, model_b) = (model(&a), model(&b));
+            let a = a.into_iter().collect::<RangeSet<_>>();
+            let b = b.into_iter().collect::<RangeSet<_>>();
+
+            prop_assert_eq!(values(&a), model_a.clone());
+            prop_assert_eq!(a.total_len(), model_a.len() as u128);
+            for value in 0..=255 {
+                prop_assert_eq!(a.contains(value), model_a.contains(&value));
+            }
+            // The ranges are sorted, and neither overlap nor touch.
+            for (before, after) in a.ranges.iter().zip(a.ranges.iter().skip(1)) {
+                prop_assert!(u16::from(*before.end()) + 1 < u16::from(*after.start()));
+            }
+
+            prop_assert_eq!(values(&a.union(&b)), &model_a | &model_b);
+            prop_assert_eq!(values(&a.intersection(&b)), &model_a & &model_b);
+            prop_assert_eq!(values(&a.difference(&b)), &model_a - &model_b);
+        }
+    }
+}
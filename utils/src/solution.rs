@@ -0,0 +1,310 @@
+use crate::{
+    args::{Args, Params},
+    budget::with_budget,
+    log::set_level,
+};
+use eyre::{OptionExt, Report, bail};
+use serde::Serialize;
+use std::{
+    convert::Infallible,
+    env,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+/// A solution to a single day's puzzle.
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    /// The names of the parameters which `parse_with_params` accepts.
+    const PARAMS: &[&str] = &[];
+
+    /// Parses the puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, Report>;
+
+    /// Parses the puzzle input, taking any puzzle constants from the given parameters.
+    ///
+    /// Solutions with parameters override this and list them in `PARAMS`, and their `parse` uses
+    /// the default values.
+    fn parse_with_params(input: &str, _params: &Params) -> Result<Self::Input, Report> {
+        Self::parse(input)
+    }
+
+    /// Returns the answer to part 1 of the puzzle.
+    fn part1(input: &Self::Input) -> Result<impl Display, Report>;
+
+    /// Returns the answer to part 2 of the puzzle.
+    ///
+    /// If part 2 hasn't been solved yet this should return `not_solved()`.
+    fn part2(input: &Self::Input) -> Result<impl Display, Report>;
+}
+
+/// The error returned for a part of a puzzle which hasn't been solved yet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NotSolved;
+
+impl Display for NotSolved {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Not solved yet")
+    }
+}
+
+impl Error for NotSolved {}
+
+/// Returns a `NotSolved` error, for the body of a part which hasn't been solved yet.
+pub fn not_solved() -> Result<Infallible, Report> {
+    Err(NotSolved.into())
+}
+
+/// Which parts of a puzzle to solve.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Parts {
+    Part1,
+    Part2,
+    #[default]
+    Both,
+}
+
+impl Parts {
+    /// Returns whether the given part (1 or 2) should be solved.
+    pub fn includes(self, part: u8) -> bool {
+        match self {
+            Self::Part1 => part == 1,
+            Self::Part2 => part == 2,
+            Self::Both => part == 1 || part == 2,
+        }
+    }
+}
+
+impl FromStr for Parts {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::Part1),
+            "2" => Ok(Self::Part2),
+            _ => bail!("Invalid part '{}', expected 1 or 2", s),
+        }
+    }
+}
+
+/// The answers to a puzzle, along with how long each step took.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answers {
+    /// How long it took to parse the input.
+    pub parse_time: Duration,
+    /// The answer to part 1, or `None` if it wasn't requested.
+    pub part1: Option<Answer>,
+    /// The answer to part 2, or `None` if it wasn't requested.
+    pub part2: Option<Answer>,
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answer {
+    /// The answer, or `None` if the part hasn't been solved yet.
+    pub value: Option<String>,
+    /// How long it took to find the answer.
+    pub time: Duration,
+}
+
+/// Parses the input with the given parameters and solves the requested parts, timing each step.
+pub fn solve<S: Solution>(input: &str, params: &Params, parts: Parts) -> Result<Answers, Report> {
+    params.check(S::PARAMS)?;
+    let start = Instant::now();
+    let input = S::parse_with_params(input, params)?;
+    let parse_time = start.elapsed();
+    let part1 = if parts.includes(1) {
+        Some(time_part(|| S::part1(&input))?)
+    } else {
+        None
+    };
+    let part2 = if parts.includes(2) {
+        Some(time_part(|| S::part2(&input))?)
+    } else {
+        None
+    };
+    Ok(Answers {
+        parse_time,
+        part1,
+        part2,
+    })
+}
+
+/// Parses the input with the given parameters without solving either part, so that parsing can be
+/// checked on its own.
+pub fn parse_only<S: Solution>(input: &str, params: &Params) -> Result<(), Report> {
+    params.check(S::PARAMS)?;
+    S::parse_with_params(input, params).map(|_| ())
+}
+
+fn time_part<T: Display>(part: impl FnOnce() -> Result<T, Report>) -> Result<Answer, Report> {
+    let start = Instant::now();
+    let value = match part() {
+        Ok(answer) => Some(answer.to_string()),
+        Err(e) if e.is::<NotSolved>() => None,
+        Err(e) => return Err(e),
+    };
+    Ok(Answer {
+        value,
+        time: start.elapsed(),
+    })
+}
+
+impl Answers {
+    /// Formats the answers as a JSON object for the given puzzle, with answers as strings and
+    /// timings in nanoseconds.
+    ///
+    /// A part which wasn't requested has a `null` answer and timing, and one which hasn't been
+    /// solved yet has a `null` answer.
+    pub fn to_json(&self, year: u16, day: u8) -> String {
+        let value = |answer: &Option<Answer>| answer.as_ref().and_then(|a| a.value.clone());
+        let time = |answer: &Option<Answer>| answer.as_ref().map(|a| a.time.as_nanos() as u64);
+        serde_json::to_string(&JsonAnswers {
+            year,
+            day,
+            part1: value(&self.part1),
+            part2: value(&self.part2),
+            timings: JsonTimings {
+                parse: self.parse_time.as_nanos() as u64,
+                part1: time(&self.part1),
+                part2: time(&self.part2),
+            },
+        })
+        .unwrap()
+    }
+}
+
+#[derive(Serialize)]
+struct JsonAnswers {
+    year: u16,
+    day: u8,
+    part1: Option<String>,
+    part2: Option<String>,
+    timings: JsonTimings,
+}
+
+#[derive(Serialize)]
+struct JsonTimings {
+    parse: u64,
+    part1: Option<u64>,
+    part2: Option<u64>,
+}
+
+/// Returns the year and day from a day crate's package name, e.g. `aoc-2024-day16`.
+fn puzzle_from_package(package: &str) -> Option<(u16, u8)> {
+    let (year, day) = package.strip_prefix("aoc-")?.split_once("-day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Reads the puzzle input, solves the requested parts and prints each answer after the given
+/// label.
+///
+/// The input, parts and parameters are given on the command line as described by
+/// [`args::usage`](crate::args::usage); by default the input is read from stdin and both parts
+/// are solved. Parts which haven't been solved yet are skipped. With `--json` the answers are
+/// printed as a single JSON object instead, for which `package` should be the day crate's
+/// package name.
+pub fn run<S: Solution>(package: &str, labels: [&str; 2]) -> Result<(), Report> {
+    let args = Args::parse(env::args())?;
+    set_level(args.level);
+    let input = args.read_input()?;
+    let answers = with_budget(args.budget, || solve::<S>(&input, &args.params, args.parts))?;
+    if args.json {
+        let (year, day) = puzzle_from_package(package)
+            .ok_or_eyre("Package name should be of the form aoc-YYYY-dayN")?;
+        println!("{}", answers.to_json(year, day));
+        return Ok(());
+    }
+    for (label, answer) in labels.into_iter().zip([answers.part1, answers.part2]) {
+        if let Some(value) = answer.and_then(|answer| answer.value) {
+            println!("{}: {}", label, value);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, Report> {
+            Ok(input
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<impl Display, Report> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part2(_input: &Self::Input) -> Result<impl Display, Report> {
+            not_solved()
+        }
+    }
+
+    #[test]
+    fn solve_both() {
+        let answers = solve::<Example>("1 2 3", &Params::default(), Parts::Both).unwrap();
+        assert_eq!(answers.part1.unwrap().value.as_deref(), Some("6"));
+        assert_eq!(answers.part2.unwrap().value, None);
+    }
+
+    #[test]
+    fn solve_one_part() {
+        let answers = solve::<Example>("1 2 3", &Params::default(), Parts::Part2).unwrap();
+        assert_eq!(answers.part1, None);
+        assert_eq!(answers.part2.unwrap().value, None);
+    }
+
+    #[test]
+    fn solve_invalid() {
+        assert!(solve::<Example>("1 x 3", &Params::default(), Parts::Both).is_err());
+    }
+
+    #[test]
+    fn solve_unknown_param() {
+        let mut params = Params::default();
+        params.insert("width", "11");
+        assert!(solve::<Example>("1 2 3", &params, Parts::Both).is_err());
+    }
+
+    #[test]
+    fn json_answers() {
+        let answers = Answers {
+            parse_time: Duration::from_nanos(10),
+            part1: Some(Answer {
+                value: Some("6".to_string()),
+                time: Duration::from_nanos(20),
+            }),
+            part2: None,
+        };
+        assert_eq!(
+            answers.to_json(2024, 16),
+            r#"{"year":2024,"day":16,"part1":"6","part2":null,"timings":{"parse":10,"part1":20,"part2":null}}"#
+        );
+    }
+
+    #[test]
+    fn package_names() {
+        assert_eq!(puzzle_from_package("aoc-2024-day16"), Some((2024, 16)));
+        assert_eq!(puzzle_from_package("aoc-2024"), None);
+        assert_eq!(puzzle_from_package("utils"), None);
+    }
+
+    #[test]
+    fn parse_parts() {
+        assert_eq!("1".parse::<Parts>().unwrap(), Parts::Part1);
+        assert_eq!("2".parse::<Parts>().unwrap(), Parts::Part2);
+        assert!("3".parse::<Parts>().is_err());
+    }
+}
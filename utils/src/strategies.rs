@@ -0,0 +1,98 @@
+//! Proptest strategies for grids and directions, for property tests here and in the days.
+//!
+//! Enable the `proptest` feature to use these from another crate.
+
+use crate::{Direction, grid::Grid};
+use proptest::{
+    arbitrary::{Arbitrary, any_with},
+    collection::vec,
+    prelude::*,
+    sample::{Select, select, subsequence},
+};
+use std::ops::RangeInclusive;
+
+/// The largest width and height of an arbitrary grid.
+pub const MAX_SIZE: usize = 12;
+
+/// Returns a strategy for grids of elements from `elements`, with a width and height in the given
+/// ranges.
+///
+/// A grid with no rows always has a width of 0, as it is built from its rows.
+pub fn grid<S: Strategy + Clone>(
+    elements: S,
+    width: RangeInclusive<usize>,
+    height: RangeInclusive<usize>,
+) -> impl Strategy<Value = Grid<S::Value>> {
+    (width, height)
+        .prop_flat_map(move |(width, height)| vec(vec(elements.clone(), width), height))
+        .prop_map(|rows| Grid::try_from(rows).unwrap())
+}
+
+/// Returns a strategy for grids of characters from `cells` of up to `max_size` in each direction,
+/// with each of the `markers` (such as `S` and `E` for the start and end) exactly once in
+/// different positions.
+pub fn chargrid_with_markers(
+    cells: &'static [char],
+    markers: &'static [char],
+    max_size: usize,
+) -> impl Strategy<Value = Grid<char>> {
+    (1..=max_size, 1..=max_size)
+        .prop_filter("Too small for the markers", move |(width, height)| {
+            width * height >= markers.len()
+        })
+        .prop_flat_map(move |(width, height)| {
+            let positions = (0..width * height).collect::<Vec<_>>();
+            (
+                vec(vec(select(cells), width), height),
+                subsequence(positions, markers.len()).prop_shuffle(),
+            )
+        })
+        .prop_map(move |(rows, positions)| {
+            let mut grid = Grid::try_from(rows).unwrap();
+            let width = grid.width();
+            for (&marker, position) in markers.iter().zip(positions) {
+                *grid.get_mut(position % width, position / width).unwrap() = marker;
+            }
+            grid
+        })
+}
+
+/// Arbitrary grids are up to `MAX_SIZE` in each direction, and may be empty.
+impl<T: Arbitrary + 'static> Arbitrary for Grid<T> {
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        grid(any_with::<T>(args).boxed(), 0..=MAX_SIZE, 0..=MAX_SIZE).boxed()
+    }
+}
+
+impl Arbitrary for Direction {
+    type Parameters = ();
+    type Strategy = Select<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        select(&Direction::ALL[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn grid_sizes(grid in grid(any::<u8>(), 2..=4, 1..=3)) {
+            prop_assert!((2..=4).contains(&grid.width()));
+            prop_assert!((1..=3).contains(&grid.height()));
+            prop_assert_eq!(grid.elements().count(), grid.width() * grid.height());
+        }
+
+        #[test]
+        fn markers_once_each(grid in chargrid_with_markers(&['.', '#'], &['S', 'E'], 6)) {
+            for marker in ['S', 'E'] {
+                prop_assert_eq!(grid.elements().filter(|(_, _, c)| **c == marker).count(), 1);
+            }
+        }
+    }
+}